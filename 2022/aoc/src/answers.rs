@@ -0,0 +1,115 @@
+use std::{fs, io::ErrorKind, path::PathBuf};
+
+use crate::input::day_dir;
+
+// Every day folder keeps the correct answers in answers.txt:
+//
+//   input 1 72017
+//   test 2 45000
+//   input 2
+//       ###...##..
+//       #..#.#..#.
+//
+// A line is <fixture> <part> <answer>, lines indented with four spaces
+// continue a multiline answer like the CRT screen of day 10
+// Lines starting with # are comments
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub fixture: String,
+    pub part: u8,
+    pub answer: String,
+}
+
+const INDENT: &str = "    ";
+
+pub fn answers_path(day: u8) -> PathBuf {
+    day_dir(day).join("answers.txt")
+}
+
+pub fn parse_answers(text: &str) -> Result<Vec<Expected>, String> {
+    let mut answers: Vec<Expected> = vec![];
+    for (index, line) in text.lines().enumerate() {
+        let error = |message: &str| format!("line {}: {}", index + 1, message);
+
+        if let Some(rest) = line.strip_prefix(INDENT) {
+            let last = answers
+                .last_mut()
+                .ok_or_else(|| error("answer continues nothing"))?;
+            if !last.answer.is_empty() {
+                last.answer.push('\n');
+            }
+            last.answer.push_str(rest);
+            continue;
+        }
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut parts = line.splitn(3, ' ');
+        let fixture = parts.next().unwrap().to_string();
+        let part = parts
+            .next()
+            .and_then(|x| x.parse::<u8>().ok())
+            .ok_or_else(|| error("wrong part"))?;
+        let answer = parts.next().unwrap_or("").to_string();
+        answers.push(Expected {
+            fixture,
+            part,
+            answer,
+        });
+    }
+
+    match answers.iter().find(|x| x.answer.is_empty()) {
+        Some(x) => Err(format!("no answer for {} part {}", x.fixture, x.part)),
+        None => Ok(answers),
+    }
+}
+
+// A day without answers.txt has nothing to verify
+pub fn read_answers(day: u8) -> Result<Vec<Expected>, String> {
+    let path = answers_path(day);
+    match fs::read_to_string(&path) {
+        Ok(text) => parse_answers(&text).map_err(|error| format!("{}: {}", path.display(), error)),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(vec![]),
+        Err(error) => Err(format!("{}: {}", path.display(), error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{parse_answers, read_answers, Expected};
+
+    fn expected(fixture: &str, part: u8, answer: &str) -> Expected {
+        Expected {
+            fixture: fixture.to_string(),
+            part,
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_answers_single_and_multiline() {
+        let text = "# comment\ninput 1 14420\ninput 2\n    ##..\n    #..#\n\ntest 1 7,5,6\n";
+        assert_eq!(
+            parse_answers(text).unwrap(),
+            vec![
+                expected("input", 1, "14420"),
+                expected("input", 2, "##..\n#..#"),
+                expected("test", 1, "7,5,6"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_answers_errors() {
+        assert!(parse_answers("    ##..").is_err());
+        assert!(parse_answers("input x 1").is_err());
+        assert!(parse_answers("input 1").is_err());
+    }
+
+    #[test]
+    fn read_answers_of_day_one() {
+        let answers = read_answers(1).unwrap();
+        assert!(answers.contains(&expected("test", 1, "24000")));
+    }
+}
@@ -0,0 +1,518 @@
+use std::path::PathBuf;
+
+use aoc_common::Packet;
+use day_21::Format;
+
+use crate::{bench::Bench, input::Input};
+
+pub const USAGE: &str = "Usage:
+    aoc run <year> <day> [--part <1|2>] [--input <file|->] [--fixture <name>]
+    aoc verify <year> [day] [--fixture <name>]
+    aoc bench <year> [day] [--part <1|2>] [--fixture <name>] [--iterations <n>]
+              [--save <file.json>] [--baseline <file.json>]
+    aoc packets [--input <file|->] [--fixture <name>] [--divider <packet>]...
+    aoc equation [--input <file|->] [--fixture <name>] [--format <text|latex|dot>]
+    aoc monkeys [--input <file|->] [--fixture <name>] [--rounds <n>] [--divisor <n>]
+                [--trace <csv|json>] [--summary] [--extrapolate]
+
+Input is read from stdin unless a file or a fixture (input, test, test2) is given
+Verify checks the answers recorded in answers.txt of every day or a single one
+Bench runs on input.txt 10 times unless told otherwise, --save writes the timings
+as a baseline JSON that --baseline compares a later run with
+Packets lists the packets of 2022 day 13 in order with the dividers and their
+decoder key, the dividers are [[2]] and [[6]] unless some are given
+Equation prints what root of 2022 day 21 compares once everything but humn is
+worked out, as text unless LaTeX or a Graphviz graph is asked for
+Monkeys plays 20 rounds of 2022 day 11 with a divisor of 3 unless told otherwise
+and prints the monkey business, --trace adds every throw and --summary the
+inspections of each round. --extrapolate skips ahead through the cycles of the
+items instead of playing every round, for any number of rounds without relief";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trace {
+    Csv,
+    Json,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        year: u16,
+        day: u8,
+        part: Option<u8>,
+        input: Input,
+    },
+    Verify {
+        year: u16,
+        day: Option<u8>,
+        fixture: Option<String>,
+    },
+    Bench(Bench),
+    Packets {
+        input: Input,
+        dividers: Vec<Packet>,
+    },
+    Equation {
+        input: Input,
+        format: Format,
+    },
+    Monkeys {
+        input: Input,
+        rounds: usize,
+        divisor: i64,
+        trace: Option<Trace>,
+        summary: bool,
+        extrapolate: bool,
+    },
+}
+
+fn parse_number<T: std::str::FromStr>(name: &str, value: Option<&String>) -> Result<T, String> {
+    let value = value.ok_or(format!("missing {}", name))?;
+    value
+        .parse::<T>()
+        .map_err(|_| format!("wrong {}: {}", name, value))
+}
+
+fn parse_run(args: &[String]) -> Result<Command, String> {
+    let year = parse_number("year", args.first())?;
+    let day = parse_number("day", args.get(1))?;
+    let mut part = None;
+    let mut input = Input::Stdin;
+
+    let mut rest = args.iter().skip(2);
+    while let Some(flag) = rest.next() {
+        match flag.as_str() {
+            "--part" | "-p" => part = Some(parse_part(rest.next())?),
+            "--input" | "-i" => {
+                let value = rest.next().ok_or("missing input")?;
+                input = Input::parse(value);
+            }
+            "--fixture" | "-f" => {
+                let value = rest.next().ok_or("missing fixture")?;
+                input = Input::Fixture(value.clone());
+            }
+            other => return Err(format!("unknown argument: {}", other)),
+        }
+    }
+
+    Ok(Command::Run {
+        year,
+        day,
+        part,
+        input,
+    })
+}
+
+fn parse_part(value: Option<&String>) -> Result<u8, String> {
+    let part: u8 = parse_number("part", value)?;
+    if part != 1 && part != 2 {
+        return Err(format!("wrong part: {}", part));
+    }
+    Ok(part)
+}
+
+fn parse_verify(args: &[String]) -> Result<Command, String> {
+    let year = parse_number("year", args.first())?;
+    let mut day = None;
+    let mut fixture = None;
+
+    let mut rest = args.iter().skip(1);
+    while let Some(flag) = rest.next() {
+        match flag.as_str() {
+            "--fixture" | "-f" => {
+                let value = rest.next().ok_or("missing fixture")?;
+                fixture = Some(value.clone());
+            }
+            value if day.is_none() && !value.starts_with('-') => {
+                day = Some(parse_number("day", Some(flag))?);
+            }
+            other => return Err(format!("unknown argument: {}", other)),
+        }
+    }
+
+    Ok(Command::Verify { year, day, fixture })
+}
+
+fn parse_bench(args: &[String]) -> Result<Command, String> {
+    let mut bench = Bench {
+        year: parse_number("year", args.first())?,
+        day: None,
+        part: None,
+        fixture: "input".to_string(),
+        iterations: 10,
+        save: None,
+        baseline: None,
+    };
+
+    let mut rest = args.iter().skip(1);
+    while let Some(flag) = rest.next() {
+        match flag.as_str() {
+            "--part" | "-p" => bench.part = Some(parse_part(rest.next())?),
+            "--fixture" | "-f" => {
+                bench.fixture = rest.next().ok_or("missing fixture")?.clone();
+            }
+            "--iterations" | "-n" => {
+                bench.iterations = parse_number("iterations", rest.next())?;
+                if bench.iterations == 0 {
+                    return Err("wrong iterations: 0".to_string());
+                }
+            }
+            "--save" => {
+                let value = rest.next().ok_or("missing save file")?;
+                bench.save = Some(PathBuf::from(value));
+            }
+            "--baseline" => {
+                let value = rest.next().ok_or("missing baseline file")?;
+                bench.baseline = Some(PathBuf::from(value));
+            }
+            value if bench.day.is_none() && !value.starts_with('-') => {
+                bench.day = Some(parse_number("day", Some(flag))?);
+            }
+            other => return Err(format!("unknown argument: {}", other)),
+        }
+    }
+
+    Ok(Command::Bench(bench))
+}
+
+fn parse_packets(args: &[String]) -> Result<Command, String> {
+    let mut input = Input::Stdin;
+    let mut dividers = vec![];
+
+    let mut rest = args.iter();
+    while let Some(flag) = rest.next() {
+        match flag.as_str() {
+            "--input" | "-i" => {
+                let value = rest.next().ok_or("missing input")?;
+                input = Input::parse(value);
+            }
+            "--fixture" | "-f" => {
+                let value = rest.next().ok_or("missing fixture")?;
+                input = Input::Fixture(value.clone());
+            }
+            "--divider" | "-d" => {
+                let value = rest.next().ok_or("missing divider")?;
+                let packet = value
+                    .parse()
+                    .map_err(|error| format!("wrong divider: {}", error))?;
+                dividers.push(packet);
+            }
+            other => return Err(format!("unknown argument: {}", other)),
+        }
+    }
+
+    if dividers.is_empty() {
+        dividers = day_13::default_dividers();
+    }
+    Ok(Command::Packets { input, dividers })
+}
+
+fn parse_equation(args: &[String]) -> Result<Command, String> {
+    let mut input = Input::Stdin;
+    let mut format = Format::Text;
+
+    let mut rest = args.iter();
+    while let Some(flag) = rest.next() {
+        match flag.as_str() {
+            "--input" | "-i" => {
+                let value = rest.next().ok_or("missing input")?;
+                input = Input::parse(value);
+            }
+            "--fixture" | "-f" => {
+                let value = rest.next().ok_or("missing fixture")?;
+                input = Input::Fixture(value.clone());
+            }
+            "--format" => {
+                format = match rest.next().ok_or("missing format")?.as_str() {
+                    "text" => Format::Text,
+                    "latex" => Format::Latex,
+                    "dot" => Format::Dot,
+                    other => return Err(format!("wrong format: {}", other)),
+                };
+            }
+            other => return Err(format!("unknown argument: {}", other)),
+        }
+    }
+
+    Ok(Command::Equation { input, format })
+}
+
+fn parse_monkeys(args: &[String]) -> Result<Command, String> {
+    let mut input = Input::Stdin;
+    let mut rounds = 20;
+    let mut divisor = None;
+    let mut trace = None;
+    let mut summary = false;
+    let mut extrapolate = false;
+
+    let mut rest = args.iter();
+    while let Some(flag) = rest.next() {
+        match flag.as_str() {
+            "--input" | "-i" => {
+                let value = rest.next().ok_or("missing input")?;
+                input = Input::parse(value);
+            }
+            "--fixture" | "-f" => {
+                let value = rest.next().ok_or("missing fixture")?;
+                input = Input::Fixture(value.clone());
+            }
+            "--rounds" | "-n" => rounds = parse_number("rounds", rest.next())?,
+            "--divisor" => {
+                let value = parse_number("divisor", rest.next())?;
+                if value < 1 {
+                    return Err(format!("wrong divisor: {}", value));
+                }
+                divisor = Some(value);
+            }
+            "--trace" => {
+                trace = match rest.next().ok_or("missing trace")?.as_str() {
+                    "csv" => Some(Trace::Csv),
+                    "json" => Some(Trace::Json),
+                    other => return Err(format!("wrong trace: {}", other)),
+                };
+            }
+            "--summary" => summary = true,
+            "--extrapolate" => extrapolate = true,
+            other => return Err(format!("unknown argument: {}", other)),
+        }
+    }
+
+    // only the counts are extrapolated, and only without relief
+    if extrapolate && (divisor.is_some_and(|x| x != 1) || trace.is_some() || summary) {
+        let error = "--extrapolate works without relief and takes no --trace or --summary";
+        return Err(error.to_string());
+    }
+    Ok(Command::Monkeys {
+        input,
+        rounds,
+        divisor: divisor.unwrap_or(if extrapolate { 1 } else { 3 }),
+        trace,
+        summary,
+        extrapolate,
+    })
+}
+
+pub fn parse_args(args: &[String]) -> Result<Command, String> {
+    match args.first().map(|x| x.as_str()) {
+        Some("run") => parse_run(&args[1..]),
+        Some("verify") => parse_verify(&args[1..]),
+        Some("bench") => parse_bench(&args[1..]),
+        Some("packets") => parse_packets(&args[1..]),
+        Some("equation") => parse_equation(&args[1..]),
+        Some("monkeys") => parse_monkeys(&args[1..]),
+        Some(other) => Err(format!("unknown command: {}", other)),
+        None => Err("missing command".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::{
+        args::{parse_args, Command, Trace},
+        bench::Bench,
+        input::Input,
+    };
+    use day_21::Format;
+
+    fn args(value: &str) -> Vec<String> {
+        value.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parse_run_with_part_and_file() {
+        let command = parse_args(&args("run 2022 17 --part 2 --input file")).unwrap();
+        assert_eq!(
+            command,
+            Command::Run {
+                year: 2022,
+                day: 17,
+                part: Some(2),
+                input: Input::File(PathBuf::from("file")),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_run_defaults_to_stdin_and_both_parts() {
+        let command = parse_args(&args("run 2022 9")).unwrap();
+        assert_eq!(
+            command,
+            Command::Run {
+                year: 2022,
+                day: 9,
+                part: None,
+                input: Input::Stdin,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_run_with_fixture() {
+        let command = parse_args(&args("run 2022 9 -f test2")).unwrap();
+        assert_eq!(
+            command,
+            Command::Run {
+                year: 2022,
+                day: 9,
+                part: None,
+                input: Input::Fixture("test2".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_verify_with_day_and_fixture() {
+        let command = parse_args(&args("verify 2022 17 --fixture test")).unwrap();
+        assert_eq!(
+            command,
+            Command::Verify {
+                year: 2022,
+                day: Some(17),
+                fixture: Some("test".to_string()),
+            }
+        );
+
+        let command = parse_args(&args("verify 2022")).unwrap();
+        assert_eq!(
+            command,
+            Command::Verify {
+                year: 2022,
+                day: None,
+                fixture: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_bench_with_defaults_and_baseline() {
+        let command = parse_args(&args("bench 2022 17 -p 2 -n 5 --baseline base.json")).unwrap();
+        assert_eq!(
+            command,
+            Command::Bench(Bench {
+                year: 2022,
+                day: Some(17),
+                part: Some(2),
+                fixture: "input".to_string(),
+                iterations: 5,
+                save: None,
+                baseline: Some(PathBuf::from("base.json")),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_packets_with_dividers() {
+        let command = parse_args(&args("packets -f test -d [[1],[]] --divider 7")).unwrap();
+        assert_eq!(
+            command,
+            Command::Packets {
+                input: Input::Fixture("test".to_string()),
+                dividers: vec!["[[1],[]]".parse().unwrap(), "7".parse().unwrap()],
+            }
+        );
+
+        let command = parse_args(&args("packets")).unwrap();
+        assert_eq!(
+            command,
+            Command::Packets {
+                input: Input::Stdin,
+                dividers: vec!["[[2]]".parse().unwrap(), "[[6]]".parse().unwrap()],
+            }
+        );
+        assert_eq!(
+            parse_args(&args("packets -d [1,")),
+            Err(
+                "wrong divider: line 1, column 4: expected a number or \"[\", found end of line"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn parse_equation_with_format() {
+        let command = parse_args(&args("equation -i - --format latex")).unwrap();
+        assert_eq!(
+            command,
+            Command::Equation {
+                input: Input::Stdin,
+                format: Format::Latex,
+            }
+        );
+
+        let command = parse_args(&args("equation -f test")).unwrap();
+        assert_eq!(
+            command,
+            Command::Equation {
+                input: Input::Fixture("test".to_string()),
+                format: Format::Text,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_monkeys_with_trace() {
+        let command = parse_args(&args("monkeys -f test -n 1 --divisor 1 --trace csv")).unwrap();
+        assert_eq!(
+            command,
+            Command::Monkeys {
+                input: Input::Fixture("test".to_string()),
+                rounds: 1,
+                divisor: 1,
+                trace: Some(Trace::Csv),
+                summary: false,
+                extrapolate: false,
+            }
+        );
+
+        let command = parse_args(&args("monkeys --summary")).unwrap();
+        assert_eq!(
+            command,
+            Command::Monkeys {
+                input: Input::Stdin,
+                rounds: 20,
+                divisor: 3,
+                trace: None,
+                summary: true,
+                extrapolate: false,
+            }
+        );
+
+        let command = parse_args(&args("monkeys -n 1000000000000 --extrapolate")).unwrap();
+        assert_eq!(
+            command,
+            Command::Monkeys {
+                input: Input::Stdin,
+                rounds: 1000000000000,
+                divisor: 1,
+                trace: None,
+                summary: false,
+                extrapolate: true,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_errors() {
+        assert!(parse_args(&args("")).is_err());
+        assert!(parse_args(&args("walk 2022 1")).is_err());
+        assert!(parse_args(&args("run 2022")).is_err());
+        assert!(parse_args(&args("run 2022 x")).is_err());
+        assert!(parse_args(&args("run 2022 1 --part 3")).is_err());
+        assert!(parse_args(&args("run 2022 1 --input")).is_err());
+        assert!(parse_args(&args("run 2022 1 --fast")).is_err());
+        assert!(parse_args(&args("verify")).is_err());
+        assert!(parse_args(&args("verify 2022 1 2")).is_err());
+        assert!(parse_args(&args("verify 2022 -f")).is_err());
+        assert!(parse_args(&args("bench 2022 1 -n 0")).is_err());
+        assert!(parse_args(&args("bench 2022 1 --save")).is_err());
+        assert!(parse_args(&args("packets --divider")).is_err());
+        assert!(parse_args(&args("equation --format svg")).is_err());
+        assert!(parse_args(&args("equation --format")).is_err());
+        assert!(parse_args(&args("monkeys --divisor 0")).is_err());
+        assert!(parse_args(&args("monkeys --trace xml")).is_err());
+        assert!(parse_args(&args("monkeys --extrapolate --divisor 3")).is_err());
+        assert!(parse_args(&args("monkeys --extrapolate --summary")).is_err());
+    }
+}
@@ -0,0 +1,310 @@
+use std::{
+    fs,
+    hint::black_box,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{ParseResult, Solution};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    input::Input,
+    memory::{peak_since, reset_peak},
+    registry::{get_parts, get_solution_for, YEAR},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bench {
+    pub year: u16,
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    pub fixture: String,
+    pub iterations: usize,
+    // write the results as a baseline for later runs
+    pub save: Option<PathBuf>,
+    // compare the results with a saved baseline
+    pub baseline: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    // "parse", "part 1" or "part 2"
+    pub stage: String,
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+    pub peak_bytes: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub fixture: String,
+    pub iterations: usize,
+    pub measurements: Vec<Measurement>,
+}
+
+impl Baseline {
+    fn find(&self, day: u8, stage: &str) -> Option<&Measurement> {
+        self.measurements
+            .iter()
+            .find(|x| x.day == day && x.stage == stage)
+    }
+}
+
+// Median, min and max of the timings
+fn stats(times: &[Duration]) -> (Duration, Duration, Duration) {
+    let mut times = times.to_vec();
+    times.sort();
+    let n = times.len();
+    let median = if n.is_multiple_of(2) {
+        (times[n / 2 - 1] + times[n / 2]) / 2
+    } else {
+        times[n / 2]
+    };
+    (median, times[0], times[n - 1])
+}
+
+// Runs the stage several times, the result is dropped outside of the timing
+fn measure<T>(
+    day: u8,
+    stage: &str,
+    iterations: usize,
+    run: impl Fn() -> ParseResult<T>,
+) -> ParseResult<Measurement> {
+    let mut times = vec![];
+    let mut peak_bytes = 0;
+    for _ in 0..iterations {
+        let start = reset_peak();
+        let now = Instant::now();
+        let result = black_box(run())?;
+        times.push(now.elapsed());
+        peak_bytes = peak_bytes.max(peak_since(start));
+        drop(result);
+    }
+
+    let (median, min, max) = stats(&times);
+    Ok(Measurement {
+        day,
+        stage: stage.to_string(),
+        median_ns: median.as_nanos() as u64,
+        min_ns: min.as_nanos() as u64,
+        max_ns: max.as_nanos() as u64,
+        peak_bytes,
+    })
+}
+
+fn format_duration(ns: u64) -> String {
+    let ns = ns as f64;
+    if ns < 1e3 {
+        format!("{:.0}ns", ns)
+    } else if ns < 1e6 {
+        format!("{:.2}µs", ns / 1e3)
+    } else if ns < 1e9 {
+        format!("{:.2}ms", ns / 1e6)
+    } else {
+        format!("{:.2}s", ns / 1e9)
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    let bytes = bytes as f64;
+    if bytes < 1024.0 {
+        format!("{}B", bytes)
+    } else if bytes < 1024.0 * 1024.0 {
+        format!("{:.1}KB", bytes / 1024.0)
+    } else {
+        format!("{:.1}MB", bytes / 1024.0 / 1024.0)
+    }
+}
+
+// Relative change of the median time, positive when slower than the baseline
+fn change(current: &Measurement, baseline: &Measurement) -> f64 {
+    if baseline.median_ns == 0 {
+        return 0.0;
+    }
+    (current.median_ns as f64 - baseline.median_ns as f64) / baseline.median_ns as f64 * 100.0
+}
+
+fn report(measurement: &Measurement, baseline: Option<&Measurement>) -> String {
+    let line = format!(
+        "day {:02} {:<6}  median {:>9}  min {:>9}  max {:>9}  peak {:>8}",
+        measurement.day,
+        measurement.stage,
+        format_duration(measurement.median_ns),
+        format_duration(measurement.min_ns),
+        format_duration(measurement.max_ns),
+        format_bytes(measurement.peak_bytes),
+    );
+    match baseline {
+        Some(baseline) => format!(
+            "{}  {:+.1}% (was {})",
+            line,
+            change(measurement, baseline),
+            format_duration(baseline.median_ns)
+        ),
+        None => line,
+    }
+}
+
+fn read_baseline(path: &PathBuf) -> Result<Baseline, String> {
+    let text =
+        fs::read_to_string(path).map_err(|error| format!("{}: {}", path.display(), error))?;
+    serde_json::from_str(&text).map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+fn bench_day(
+    options: &Bench,
+    day: u8,
+    solution: &dyn Solution,
+    input: &str,
+) -> ParseResult<Vec<Measurement>> {
+    let mut measurements = vec![];
+    let iterations = options.iterations;
+
+    // parts include parsing, days without a separate parser only time the parts
+    if options.part.is_none() && solution.parse(input).is_some() {
+        measurements.push(measure(day, "parse", iterations, || {
+            solution.parse(input).unwrap()
+        })?);
+    }
+
+    let parts = match options.part {
+        Some(part) => vec![part],
+        None => get_parts(day),
+    };
+    for part in parts {
+        let stage = format!("part {}", part);
+        measurements.push(measure(day, &stage, iterations, || {
+            solution.solve(part, input).unwrap()
+        })?);
+    }
+    Ok(measurements)
+}
+
+pub fn bench(options: &Bench) -> Result<(), String> {
+    if options.year != YEAR {
+        return Err(format!("no solutions for {}", options.year));
+    }
+    let baseline = match &options.baseline {
+        Some(path) => Some(read_baseline(path)?),
+        None => None,
+    };
+    if let Some(baseline) = &baseline {
+        if baseline.fixture != options.fixture {
+            return Err(format!("baseline was measured on {}", baseline.fixture));
+        }
+    }
+
+    let days = match options.day {
+        Some(day) => vec![day],
+        None => (1..=25).collect(),
+    };
+
+    println!(
+        "{} iterations on {}, parts include parsing\n",
+        options.iterations, options.fixture
+    );
+    let mut measurements = vec![];
+    for day in days {
+        let input = Input::Fixture(options.fixture.clone());
+        let solution = get_solution_for(options.year, day, &input)
+            .ok_or(format!("no solution for day {}", day))?;
+        let name = input.name(day);
+        let input = input.read(day)?;
+        let day_measurements = bench_day(options, day, solution, &input)
+            .map_err(|error| format!("{}: {}", name, error))?;
+        for measurement in day_measurements {
+            let previous = baseline
+                .as_ref()
+                .and_then(|x| x.find(measurement.day, &measurement.stage));
+            println!("{}", report(&measurement, previous));
+            measurements.push(measurement);
+        }
+    }
+
+    if let Some(path) = &options.save {
+        let baseline = Baseline {
+            fixture: options.fixture.clone(),
+            iterations: options.iterations,
+            measurements,
+        };
+        let json = serde_json::to_string_pretty(&baseline).map_err(|error| error.to_string())?;
+        fs::write(path, json).map_err(|error| format!("{}: {}", path.display(), error))?;
+        println!("\nbaseline saved to {}", path.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::bench::{
+        change, format_bytes, format_duration, measure, stats, Baseline, Measurement,
+    };
+
+    fn measurement(stage: &str, median_ns: u64) -> Measurement {
+        Measurement {
+            day: 1,
+            stage: stage.to_string(),
+            median_ns,
+            min_ns: median_ns,
+            max_ns: median_ns,
+            peak_bytes: 0,
+        }
+    }
+
+    #[test]
+    fn stats_of_odd_and_even_runs() {
+        let ms = Duration::from_millis;
+        assert_eq!(stats(&[ms(3), ms(1), ms(2)]), (ms(2), ms(1), ms(3)));
+        assert_eq!(stats(&[ms(4), ms(1), ms(2), ms(8)]), (ms(3), ms(1), ms(8)));
+    }
+
+    #[test]
+    fn measure_counts_peak_memory() {
+        let result = measure(1, "parse", 3, || Ok(vec![0_u64; 1000])).unwrap();
+        assert_eq!(result.stage, "parse");
+        assert!(result.min_ns <= result.median_ns && result.median_ns <= result.max_ns);
+        assert!(result.peak_bytes >= 8000);
+    }
+
+    #[test]
+    fn format_units() {
+        assert_eq!(format_duration(512), "512ns");
+        assert_eq!(format_duration(1_500), "1.50µs");
+        assert_eq!(format_duration(2_250_000), "2.25ms");
+        assert_eq!(format_duration(3_000_000_000), "3.00s");
+        assert_eq!(format_bytes(100), "100B");
+        assert_eq!(format_bytes(2048), "2.0KB");
+        assert_eq!(format_bytes(3 << 20), "3.0MB");
+    }
+
+    #[test]
+    fn change_against_baseline() {
+        assert_eq!(
+            change(&measurement("part 1", 150), &measurement("part 1", 100)),
+            50.0
+        );
+        assert_eq!(
+            change(&measurement("part 1", 50), &measurement("part 1", 100)),
+            -50.0
+        );
+    }
+
+    #[test]
+    fn baseline_json_round_trip() {
+        let baseline = Baseline {
+            fixture: "input".to_string(),
+            iterations: 10,
+            measurements: vec![measurement("parse", 10), measurement("part 1", 20)],
+        };
+        let json = serde_json::to_string(&baseline).unwrap();
+        let parsed: Baseline = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, baseline);
+        assert_eq!(parsed.find(1, "part 1").unwrap().median_ns, 20);
+        assert!(parsed.find(2, "part 1").is_none());
+    }
+}
@@ -0,0 +1,93 @@
+use std::{fs, path::PathBuf};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+    // A file stored next to the day sources, like input.txt or test2.txt
+    Fixture(String),
+}
+
+impl Input {
+    pub fn parse(value: &str) -> Input {
+        if value == "-" {
+            Input::Stdin
+        } else {
+            Input::File(PathBuf::from(value))
+        }
+    }
+
+    // Where the input comes from, to point diagnostics at it
+    pub fn name(&self, day: u8) -> String {
+        match self {
+            Input::Stdin => "stdin".to_string(),
+            Input::File(path) => path.display().to_string(),
+            Input::Fixture(name) => fixture_path(day, name).display().to_string(),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, String> {
+        match self {
+            Input::Stdin => Ok(aoc_common::read_input()),
+            Input::File(path) => read_file(path.clone()),
+            Input::Fixture(name) => read_file(fixture_path(day, name)),
+        }
+    }
+}
+
+fn read_file(path: PathBuf) -> Result<String, String> {
+    fs::read_to_string(&path).map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+pub fn day_dir(day: u8) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day_{:02}", day))
+}
+
+// "test", "test.txt" and "test2" all name files in the day folder
+pub fn fixture_path(day: u8, name: &str) -> PathBuf {
+    let file = if name.ends_with(".txt") {
+        name.to_string()
+    } else {
+        format!("{}.txt", name)
+    };
+    day_dir(day).join(file)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::input::{fixture_path, Input};
+
+    #[test]
+    fn input_parse() {
+        assert_eq!(Input::parse("-"), Input::Stdin);
+        assert_eq!(Input::parse("a.txt"), Input::File(PathBuf::from("a.txt")));
+    }
+
+    #[test]
+    fn input_name() {
+        assert_eq!(Input::Stdin.name(1), "stdin");
+        assert_eq!(Input::parse("a.txt").name(1), "a.txt");
+        assert!(Input::Fixture("test".to_string())
+            .name(1)
+            .ends_with("day_01/test.txt"));
+    }
+
+    #[test]
+    fn fixture_path_adds_extension() {
+        assert!(fixture_path(9, "test2").ends_with("day_09/test2.txt"));
+        assert!(fixture_path(9, "test2.txt").ends_with("day_09/test2.txt"));
+    }
+
+    #[test]
+    fn input_read_fixture() {
+        let input = Input::Fixture("test".to_string()).read(1).unwrap();
+        assert!(input.starts_with("1000\n"));
+
+        let error = Input::Fixture("missing".to_string()).read(1);
+        assert!(error.is_err());
+    }
+}
@@ -0,0 +1,135 @@
+use aoc_common::Packet;
+use args::{parse_args, Command, Trace, USAGE};
+use bench::bench;
+use day_21::Format;
+use input::Input;
+use registry::{get_parts, get_solution_for};
+use std::{env, process};
+use verify::verify;
+
+mod answers;
+mod args;
+mod bench;
+mod input;
+mod memory;
+mod registry;
+mod verify;
+
+fn run(year: u16, day: u8, part: Option<u8>, input: Input) -> Result<(), String> {
+    let solution = get_solution_for(year, day, &input)
+        .ok_or(format!("no solution for {} day {}", year, day))?;
+    let name = input.name(day);
+    let input = input.read(day)?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => get_parts(day),
+    };
+    for part in parts {
+        let answer = solution
+            .solve(part, &input)
+            .unwrap()
+            .map_err(|error| format!("{}: {}", name, error))?
+            .to_string();
+        // multiline answers like the CRT screen of day 10 start on their own line
+        if answer.contains('\n') {
+            println!("Part {}:\n{}", part, answer);
+        } else {
+            println!("Part {}: {}", part, answer);
+        }
+    }
+    Ok(())
+}
+
+// The packets of day 13 in order with the dividers among them
+fn packets(input: Input, dividers: Vec<Packet>) -> Result<(), String> {
+    let name = input.name(13);
+    let input = input.read(13)?;
+    let store = day_13::read_packets(&input).map_err(|error| format!("{}: {}", name, error))?;
+    let key = store.decoder_key(&dividers);
+    let mut all = store;
+    for divider in &dividers {
+        all.insert(divider.clone());
+    }
+    println!("{}", all.listing(&dividers));
+    println!("Decoder key: {}", key);
+    Ok(())
+}
+
+// The equation of day 21 that humn has to solve
+fn equation(input: Input, format: Format) -> Result<(), String> {
+    let name = input.name(21);
+    let input = input.read(21)?;
+    let equation =
+        day_21::render_equation(&input, format).map_err(|error| format!("{}: {}", name, error))?;
+    println!("{}", equation);
+    Ok(())
+}
+
+// The rounds of day 11 with what happened in them
+fn monkeys(
+    input: Input,
+    rounds: usize,
+    divisor: i64,
+    trace: Option<Trace>,
+    summary: bool,
+    extrapolate: bool,
+) -> Result<(), String> {
+    let name = input.name(11);
+    let input = input.read(11)?;
+    if extrapolate {
+        let business = day_11::monkey_business_after(&input, rounds)
+            .map_err(|error| format!("{}: {}", name, error))?;
+        println!("Monkey business: {}", business);
+        return Ok(());
+    }
+    let simulator = day_11::simulate(&input, rounds, divisor, trace.is_some())
+        .map_err(|error| format!("{}: {}", name, error))?;
+    match trace {
+        Some(Trace::Csv) => println!("{}", simulator.trace_csv()),
+        Some(Trace::Json) => {
+            let json = simulator.trace_json().map_err(|error| error.to_string())?;
+            println!("{}", json);
+        }
+        None => {}
+    }
+    if summary {
+        println!("{}", simulator.summary());
+    }
+    println!("Monkey business: {}", simulator.monkey_business());
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    // only wrong arguments need the usage, parse errors point into the input
+    let command = parse_args(&args).map_err(|error| format!("{}\n\n{}", error, USAGE));
+    let result = command.and_then(|command| match command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+        } => run(year, day, part, input).map(|_| true),
+        Command::Verify { year, day, fixture } => verify(year, day, fixture),
+        Command::Bench(options) => bench(&options).map(|_| true),
+        Command::Packets { input, dividers } => packets(input, dividers).map(|_| true),
+        Command::Equation { input, format } => equation(input, format).map(|_| true),
+        Command::Monkeys {
+            input,
+            rounds,
+            divisor,
+            trace,
+            summary,
+            extrapolate,
+        } => monkeys(input, rounds, divisor, trace, summary, extrapolate).map(|_| true),
+    });
+    match result {
+        Ok(true) => {}
+        // failed checks are already reported
+        Ok(false) => process::exit(1),
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use aoc_common::Solution;
+
+use crate::input::Input;
+
+// The only year solved in Rust, 2021 is in Go and 2023 is in C
+pub const YEAR: u16 = 2022;
+
+const SOLUTIONS: [&dyn Solution; 25] = [
+    &day_01::Day01,
+    &day_02::Day02,
+    &day_03::Day03,
+    &day_04::Day04,
+    &day_05::Day05,
+    &day_06::Day06,
+    &day_07::Day07,
+    &day_08::Day08,
+    &day_09::Day09,
+    &day_10::Day10,
+    &day_11::Day11,
+    &day_12::Day12,
+    &day_13::Day13,
+    &day_14::Day14,
+    &day_15::Day15::PUZZLE,
+    &day_16::Day16,
+    &day_17::Day17,
+    &day_18::Day18,
+    &day_19::Day19,
+    &day_20::Day20,
+    &day_21::Day21,
+    &day_22::Day22,
+    &day_23::Day23,
+    &day_24::Day24,
+    &day_25::Day25,
+];
+
+pub fn get_solution(year: u16, day: u8) -> Option<&'static dyn Solution> {
+    if year != YEAR || day == 0 {
+        return None;
+    }
+    SOLUTIONS.get(day as usize - 1).copied()
+}
+
+// The example of day 15 asks about a smaller area than the puzzle input,
+// so test.txt is solved with the settings of the example
+pub fn get_solution_for(year: u16, day: u8, input: &Input) -> Option<&'static dyn Solution> {
+    let example = matches!(input, Input::Fixture(name) if name.trim_end_matches(".txt") == "test");
+    match (year, day) {
+        (YEAR, 15) if example => Some(&day_15::Day15::EXAMPLE),
+        _ => get_solution(year, day),
+    }
+}
+
+// Day 25 has a single puzzle
+pub fn get_parts(day: u8) -> Vec<u8> {
+    if day == 25 {
+        vec![1]
+    } else {
+        vec![1, 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        input::Input,
+        registry::{get_parts, get_solution, get_solution_for},
+    };
+
+    #[test]
+    fn get_solution_by_year_and_day() {
+        assert!(get_solution(2022, 1).is_some());
+        assert!(get_solution(2022, 25).is_some());
+        assert!(get_solution(2022, 0).is_none());
+        assert!(get_solution(2022, 26).is_none());
+        assert!(get_solution(2021, 1).is_none());
+    }
+
+    #[test]
+    fn get_solution_solves_day_one() {
+        let input = "1000\n2000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+        let solution = get_solution(2022, 1).unwrap();
+        assert_eq!(solution.part_one(input).unwrap().to_string(), "24000");
+        assert_eq!(solution.part_two(input).unwrap().to_string(), "45000");
+    }
+
+    #[test]
+    fn get_solution_for_the_day_15_example() {
+        let input = include_str!("../../day_15/test.txt");
+        let example = Input::Fixture("test".to_string());
+        let solution = get_solution_for(2022, 15, &example).unwrap();
+        assert_eq!(solution.part_one(input).unwrap().to_string(), "26");
+        assert_eq!(solution.part_two(input).unwrap().to_string(), "56000011");
+        let solution = get_solution_for(2022, 15, &Input::Stdin).unwrap();
+        assert_eq!(solution.part_one(input).unwrap().to_string(), "0");
+    }
+
+    #[test]
+    fn get_parts_of_last_day() {
+        assert_eq!(get_parts(24), vec![1, 2]);
+        assert_eq!(get_parts(25), vec![1]);
+    }
+}
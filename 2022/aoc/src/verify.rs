@@ -0,0 +1,206 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+};
+
+use aoc_common::Solution;
+
+use crate::{
+    answers::{read_answers, Expected},
+    input::Input,
+    registry::{get_solution_for, YEAR},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    // Failed now but passed on the previous verify run
+    Regression,
+}
+
+impl Status {
+    fn new(passed: bool, passed_before: bool) -> Status {
+        match (passed, passed_before) {
+            (true, _) => Status::Pass,
+            (false, true) => Status::Regression,
+            (false, false) => Status::Fail,
+        }
+    }
+}
+
+// day, fixture and part of a single check
+type Key = (u8, String, u8);
+
+// Results of the previous run are kept under target/ to spot regressions
+fn history_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("target")
+        .join("aoc-verify.txt")
+}
+
+fn parse_history(text: &str) -> BTreeMap<Key, bool> {
+    text.lines()
+        .filter_map(|line| {
+            let mut parts = line.split(' ');
+            let day = parts.next()?.parse().ok()?;
+            let fixture = parts.next()?.to_string();
+            let part = parts.next()?.parse().ok()?;
+            let passed = parts.next()? == "pass";
+            Some(((day, fixture, part), passed))
+        })
+        .collect()
+}
+
+fn format_history(history: &BTreeMap<Key, bool>) -> String {
+    history
+        .iter()
+        .map(|((day, fixture, part), passed)| {
+            let status = if *passed { "pass" } else { "fail" };
+            format!("{} {} {} {}\n", day, fixture, part, status)
+        })
+        .collect()
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+// A panicking part fails its check instead of stopping the whole run
+fn solve(solution: &dyn Solution, part: u8, input: &str) -> Result<String, String> {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| solution.solve(part, input)));
+    match result {
+        Ok(Some(Ok(answer))) => Ok(answer.to_string()),
+        Ok(Some(Err(error))) => Err(error.to_string()),
+        Ok(None) => Err(format!("no part {}", part)),
+        Err(payload) => Err(format!("panicked: {}", panic_message(payload))),
+    }
+}
+
+fn show(answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("\n{}\n", answer)
+    } else {
+        answer.to_string()
+    }
+}
+
+fn check(day: u8, expected: &Expected) -> Result<(), String> {
+    let input = Input::Fixture(expected.fixture.clone());
+    let solution =
+        get_solution_for(YEAR, day, &input).ok_or(format!("no solution for day {}", day))?;
+    let answer = solve(solution, expected.part, &input.read(day)?)?;
+    if answer == expected.answer {
+        Ok(())
+    } else {
+        Err(format!(
+            "expected {} got {}",
+            show(&expected.answer),
+            show(&answer)
+        ))
+    }
+}
+
+// Runs every recorded answer and returns true when all of them pass
+pub fn verify(year: u16, day: Option<u8>, fixture: Option<String>) -> Result<bool, String> {
+    if year != YEAR {
+        return Err(format!("no solutions for {}", year));
+    }
+    let days = match day {
+        Some(day) => vec![day],
+        None => (1..=25).collect(),
+    };
+
+    let path = history_path();
+    let mut history = parse_history(&fs::read_to_string(&path).unwrap_or_default());
+    let mut statuses = vec![];
+
+    // panics are reported as failed checks, keep stderr clean meanwhile
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    for day in days {
+        let answers = read_answers(day)?;
+        for expected in answers
+            .iter()
+            .filter(|x| fixture.is_none() || fixture.as_ref() == Some(&x.fixture))
+        {
+            let result = check(day, expected);
+            let key = (day, expected.fixture.clone(), expected.part);
+            let passed_before = history.get(&key).copied().unwrap_or(false);
+            let status = Status::new(result.is_ok(), passed_before);
+            history.insert(key, result.is_ok());
+            statuses.push(status);
+
+            let label = format!("day {:02} {} part {}", day, expected.fixture, expected.part);
+            match (status, result) {
+                (Status::Pass, _) => println!("{}: pass", label),
+                (Status::Fail, Err(error)) => println!("{}: fail, {}", label, error),
+                (_, Err(error)) => println!("{}: REGRESSION, {}", label, error),
+                (_, Ok(_)) => unreachable!(),
+            }
+        }
+    }
+
+    panic::set_hook(hook);
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|error| error.to_string())?;
+    }
+    fs::write(&path, format_history(&history)).map_err(|error| error.to_string())?;
+
+    let count = |status: Status| statuses.iter().filter(|x| **x == status).count();
+    println!(
+        "\n{} passed, {} failed, {} regressions",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Regression)
+    );
+    Ok(count(Status::Fail) + count(Status::Regression) == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::verify::{format_history, parse_history, solve, Status};
+
+    #[test]
+    fn status_from_previous_run() {
+        assert_eq!(Status::new(true, false), Status::Pass);
+        assert_eq!(Status::new(true, true), Status::Pass);
+        assert_eq!(Status::new(false, false), Status::Fail);
+        assert_eq!(Status::new(false, true), Status::Regression);
+    }
+
+    #[test]
+    fn history_round_trip() {
+        let mut history = BTreeMap::new();
+        history.insert((17, "input".to_string(), 2), true);
+        history.insert((15, "test".to_string(), 1), false);
+
+        let text = format_history(&history);
+        assert_eq!(text, "15 test 1 fail\n17 input 2 pass\n");
+        assert_eq!(parse_history(&text), history);
+    }
+
+    #[test]
+    fn solve_catches_panics() {
+        let solution = crate::registry::get_solution(2022, 10).unwrap();
+        assert!(solve(solution, 1, "noop\n").is_err());
+        assert!(solve(solution, 3, "noop\n").is_err());
+        assert_eq!(
+            solve(solution, 1, "jump 3\n"),
+            Err("line 1, column 1: expected \"noop\" or \"addx\", found \"jump\"".to_string())
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use std::{collections::HashMap, hash::Hash};
+
+// States from `start` on repeat every `length` steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    // The step before the end of the first cycle with the same state
+    pub fn position(&self, step: usize) -> usize {
+        if step < self.start + self.length {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+
+    // Value of a metric at any step, like a height that grows by the same
+    // amount every cycle. `values` has the metric of every step up to the
+    // end of the first cycle, so `start + length + 1` of them
+    pub fn extrapolate(&self, values: &[i64], step: usize) -> i64 {
+        if step < self.start + self.length {
+            return values[step];
+        }
+        let growth = values[self.start + self.length] - values[self.start];
+        let cycles = ((step - self.start) / self.length) as i64;
+        values[self.position(step)] + cycles * growth
+    }
+}
+
+// Remembers the step of every state fingerprint until one of them comes back
+#[derive(Debug, Clone)]
+pub struct CycleFinder<K> {
+    seen: HashMap<K, usize>,
+    step: usize,
+}
+
+impl<K: Eq + Hash> Default for CycleFinder<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash> CycleFinder<K> {
+    pub fn new() -> CycleFinder<K> {
+        CycleFinder {
+            seen: HashMap::new(),
+            step: 0,
+        }
+    }
+
+    // Fingerprint of the next step, the steps are counted from 0
+    pub fn push(&mut self, key: K) -> Option<Cycle> {
+        let step = self.step;
+        self.step += 1;
+        self.seen.insert(key, step).map(|start| Cycle {
+            start,
+            length: step - start,
+        })
+    }
+}
+
+pub fn find_cycle<K: Eq + Hash>(states: impl IntoIterator<Item = K>) -> Option<Cycle> {
+    let mut finder = CycleFinder::new();
+    states.into_iter().find_map(|key| finder.push(key))
+}
+
+// Brent's algorithm keeps only two states around, the step function has to
+// be deterministic and the states have to repeat at some point
+pub fn brent<S: Clone + Eq>(start: S, mut next: impl FnMut(&S) -> S) -> Cycle {
+    // find the length with a tortoise that jumps to the hare at every power of two
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = next(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = next(&hare);
+        length += 1;
+    }
+
+    // the first state that is equal to the one a length later starts the cycle
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..length {
+        hare = next(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cycle::{brent, find_cycle, Cycle, CycleFinder};
+
+    fn step(x: &u32) -> u32 {
+        (x * x + 1) % 255
+    }
+
+    #[test]
+    fn brent_and_hash_map_agree() {
+        for start in [0, 3, 100] {
+            let states = std::iter::successors(Some(start), |x| Some(step(x)));
+            let found = find_cycle(states).unwrap();
+            assert_eq!(brent(start, step), found, "start {}", start);
+        }
+        // 0 -> 1 -> 2 -> 5 -> 26 -> 167 -> 95 -> 101 -> 2
+        assert_eq!(
+            brent(0, step),
+            Cycle {
+                start: 2,
+                length: 6
+            }
+        );
+    }
+
+    #[test]
+    fn finder_reports_the_first_repeat() {
+        let mut finder = CycleFinder::new();
+        for key in ["a", "b", "c", "d"] {
+            assert_eq!(finder.push(key), None);
+        }
+        assert_eq!(
+            finder.push("b"),
+            Some(Cycle {
+                start: 1,
+                length: 3
+            })
+        );
+        assert_eq!(find_cycle([1, 2, 3]), None);
+    }
+
+    #[test]
+    fn extrapolate_metric() {
+        let cycle = Cycle {
+            start: 2,
+            length: 3,
+        };
+        assert_eq!(cycle.position(4), 4);
+        assert_eq!(cycle.position(5), 2);
+        assert_eq!(cycle.position(9), 3);
+
+        // grows by 1, 4 and 2 in every cycle after two steps of 5
+        let values = [0, 5, 10, 11, 15, 17];
+        let expected = (0..20).scan(0, |value, step| {
+            *value += match step {
+                0 => 0,
+                1 | 2 => 5,
+                _ => [1, 4, 2][step % 3],
+            };
+            Some(*value)
+        });
+        for (step, value) in expected.enumerate() {
+            assert_eq!(cycle.extrapolate(&values, step), value, "step {}", step);
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use crate::vector::Vector;
+
+// Turns the grid by 45 degrees, x + y and x - y become the axes so that
+// Manhattan balls become axis aligned squares
+pub fn rotate(v: Vector) -> Vector {
+    Vector::new(v.x + v.y, v.x - v.y)
+}
+
+// Only rotated points with both coordinates even or both odd are on the grid
+pub fn unrotate(v: Vector) -> Option<Vector> {
+    if (v.x - v.y) % 2 != 0 {
+        return None;
+    }
+    Some(Vector::new((v.x + v.y) / 2, (v.x - v.y) / 2))
+}
+
+// A line at 45 degrees, all points with x + y or x - y equal to a value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Line {
+    Sum(isize),
+    Difference(isize),
+}
+
+// Every point within a Manhattan distance of the centre
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Diamond {
+    pub centre: Vector,
+    pub radius: isize,
+}
+
+impl Diamond {
+    pub fn new(centre: Vector, radius: isize) -> Diamond {
+        Diamond { centre, radius }
+    }
+
+    // The smallest diamond around the centre that reaches a point
+    pub fn reaching(centre: Vector, point: Vector) -> Diamond {
+        Diamond::new(centre, centre.manhattan(&point))
+    }
+
+    pub fn contains(&self, point: &Vector) -> bool {
+        self.centre.manhattan(point) <= self.radius
+    }
+
+    // The square of the diamond in rotated coordinates
+    pub fn rect(&self) -> Rect {
+        let centre = rotate(self.centre);
+        let radius = Vector::new(self.radius, self.radius);
+        Rect::new(centre - radius, centre + radius)
+    }
+
+    // Two diamonds that are a single line of points apart have that line
+    // between them, so it is the only place left for anything they don't cover
+    pub fn gap(&self, other: &Diamond) -> Option<Line> {
+        if self.centre.manhattan(&other.centre) != self.radius + other.radius + 2 {
+            return None;
+        }
+        let (a, b) = (self.rect(), other.rect());
+        if a.max.x + 2 == b.min.x {
+            Some(Line::Sum(a.max.x + 1))
+        } else if b.max.x + 2 == a.min.x {
+            Some(Line::Sum(b.max.x + 1))
+        } else if a.max.y + 2 == b.min.y {
+            Some(Line::Difference(a.max.y + 1))
+        } else {
+            Some(Line::Difference(b.max.y + 1))
+        }
+    }
+}
+
+// Rectangle of all points between the corners, including the edges
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub min: Vector,
+    pub max: Vector,
+}
+
+impl Rect {
+    pub fn new(min: Vector, max: Vector) -> Rect {
+        Rect { min, max }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y
+    }
+
+    pub fn area(&self) -> isize {
+        if self.is_empty() {
+            return 0;
+        }
+        (self.max.x - self.min.x + 1) * (self.max.y - self.min.y + 1)
+    }
+
+    pub fn contains(&self, point: &Vector) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let rect = Rect::new(
+            Vector::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y)),
+            Vector::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y)),
+        );
+        (!rect.is_empty()).then_some(rect)
+    }
+
+    // The parts that are not in the other rectangle, at most four of them:
+    // full height strips left and right and the rest below and above
+    pub fn subtract(&self, other: &Rect) -> Vec<Rect> {
+        let Some(cut) = self.intersection(other) else {
+            return vec![*self];
+        };
+        let (min, max) = (self.min, self.max);
+        [
+            Rect::new(min, Vector::new(cut.min.x - 1, max.y)),
+            Rect::new(Vector::new(cut.max.x + 1, min.y), max),
+            Rect::new(
+                Vector::new(cut.min.x, min.y),
+                Vector::new(cut.max.x, cut.min.y - 1),
+            ),
+            Rect::new(
+                Vector::new(cut.min.x, cut.max.y + 1),
+                Vector::new(cut.max.x, max.y),
+            ),
+        ]
+        .into_iter()
+        .filter(|rect| !rect.is_empty())
+        .collect()
+    }
+
+    // A grid point inside of a box of the grid that is in this rotated
+    // rectangle. The box limits the second rotated coordinate v for every
+    // first one u between two lower and two upper lines, so the widest choice
+    // of v is where two of the lines cross
+    pub fn point_within(&self, min: Vector, max: Vector) -> Option<Vector> {
+        let lower = |u: isize| self.min.y.max(2 * min.x - u).max(u - 2 * max.y);
+        let upper = |u: isize| self.max.y.min(2 * max.x - u).min(u - 2 * min.y);
+        let crossings = [
+            self.min.x,
+            self.max.x,
+            2 * min.x - self.min.y,
+            self.min.y + 2 * max.y,
+            min.x + max.y,
+            2 * max.x - self.max.y,
+            self.max.y + 2 * min.y,
+            max.x + min.y,
+        ];
+        crossings
+            .iter()
+            .flat_map(|u| [u - 1, *u, u + 1])
+            .filter(|u| (self.min.x..=self.max.x).contains(u))
+            .find_map(|u| {
+                // v has to be even when u is and odd when u is odd
+                let v = lower(u) + (u - lower(u)).rem_euclid(2);
+                (v <= upper(u)).then(|| unrotate(Vector::new(u, v)).unwrap())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        diamond::{rotate, unrotate, Diamond, Line, Rect},
+        Vector,
+    };
+
+    #[test]
+    fn rotation() {
+        for x in -3..3 {
+            for y in -3..3 {
+                let v = Vector::new(x, y);
+                assert_eq!(unrotate(rotate(v)), Some(v));
+            }
+        }
+        assert_eq!(unrotate(Vector::new(1, 0)), None);
+        assert_eq!(unrotate(Vector::new(-1, 2)), None);
+    }
+
+    #[test]
+    fn diamond_is_its_rect() {
+        let diamond = Diamond::reaching(Vector::new(3, -1), Vector::new(1, 1));
+        assert_eq!(diamond.radius, 4);
+        let rect = diamond.rect();
+        let mut count = 0;
+        for x in -10..10 {
+            for y in -10..10 {
+                let v = Vector::new(x, y);
+                assert_eq!(diamond.contains(&v), rect.contains(&rotate(v)), "{:?}", v);
+                count += diamond.contains(&v) as isize;
+            }
+        }
+        // every other point of the square is on the grid, plus a corner
+        assert_eq!(count, (rect.area() + 1) / 2);
+        assert_eq!(count, 2 * 4 * 5 + 1);
+    }
+
+    #[test]
+    fn subtraction() {
+        let rect = Rect::new(Vector::new(0, 0), Vector::new(9, 9));
+        let hole = Rect::new(Vector::new(3, 3), Vector::new(5, 6));
+        let parts = rect.subtract(&hole);
+        assert_eq!(parts.len(), 4);
+        assert_eq!(parts.iter().map(|x| x.area()).sum::<isize>(), 100 - 12);
+        for x in 0..10 {
+            for y in 0..10 {
+                let v = Vector::new(x, y);
+                let inside = parts.iter().filter(|part| part.contains(&v)).count();
+                assert_eq!(inside, !hole.contains(&v) as usize, "{:?}", v);
+            }
+        }
+
+        let corner = Rect::new(Vector::new(-5, -5), Vector::new(4, 4));
+        assert_eq!(
+            rect.subtract(&corner)
+                .iter()
+                .map(|x| x.area())
+                .sum::<isize>(),
+            75
+        );
+        assert!(rect.subtract(&rect).is_empty());
+        let apart = Rect::new(Vector::new(20, 0), Vector::new(30, 5));
+        assert_eq!(rect.subtract(&apart), vec![rect]);
+    }
+
+    #[test]
+    fn gap_between_diamonds() {
+        let a = Diamond::new(Vector::new(0, 0), 2);
+        // the points at distance 3 of a and 2 of b
+        let b = Diamond::new(Vector::new(3, 4), 3);
+        assert_eq!(a.gap(&b), Some(Line::Sum(3)));
+        assert_eq!(b.gap(&a), Some(Line::Sum(3)));
+        let c = Diamond::new(Vector::new(4, -2), 2);
+        assert_eq!(a.gap(&c), Some(Line::Difference(3)));
+        assert_eq!(a.gap(&Diamond::new(Vector::new(4, 0), 2)), None);
+    }
+
+    #[test]
+    fn points_within_boxes() {
+        let (min, max) = (Vector::new(0, 0), Vector::new(5, 3));
+        // every small rectangle around the rotated box
+        let rects = (-4..14).flat_map(|u| {
+            (-8..8).flat_map(move |v| {
+                (0..4).flat_map(move |w| {
+                    (0..4).map(move |h| Rect::new(Vector::new(u, v), Vector::new(u + w, v + h)))
+                })
+            })
+        });
+        for rect in rects {
+            let expected = (min.x..=max.x)
+                .flat_map(|x| (min.y..=max.y).map(move |y| Vector::new(x, y)))
+                .any(|v| rect.contains(&rotate(v)));
+            let found = rect.point_within(min, max);
+            assert_eq!(found.is_some(), expected, "{:?}", rect);
+            if let Some(v) = found {
+                assert!(
+                    rect.contains(&rotate(v)) && (0..=5).contains(&v.x) && (0..=3).contains(&v.y)
+                );
+            }
+        }
+    }
+}
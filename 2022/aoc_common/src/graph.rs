@@ -0,0 +1,452 @@
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry as MapEntry, BinaryHeap, HashMap, HashSet, VecDeque},
+    fmt::Debug,
+    hash::Hash,
+    ops::Add,
+};
+
+#[derive(Debug, Clone)]
+pub struct Node<K, T> {
+    pub id: K,
+    pub payload: T,
+}
+
+impl<K, T> Node<K, T> {
+    pub fn new(id: K, payload: T) -> Node<K, T> {
+        Node { id, payload }
+    }
+}
+
+#[derive(Debug)]
+pub struct Graph<K, P>
+where
+    K: Eq + Hash + Copy + Debug,
+{
+    pub nodes: HashMap<K, Node<K, P>>,
+    pub edges: HashMap<K, Vec<(K, i32)>>,
+}
+
+impl<K: Eq + Hash + Copy + Debug, P> Default for Graph<K, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash + Copy + Debug, P> Graph<K, P> {
+    pub fn new() -> Graph<K, P> {
+        Graph {
+            nodes: HashMap::new(),
+            edges: HashMap::new(),
+        }
+    }
+
+    pub fn add_node(&mut self, id: K, payload: P) -> Option<&Node<K, P>> {
+        if self.nodes.contains_key(&id) {
+            return self.nodes.get(&id);
+        }
+        self.nodes.insert(id, Node::new(id, payload));
+        self.nodes.get(&id)
+    }
+
+    pub fn get_node(&self, id: &K) -> Option<&Node<K, P>> {
+        self.nodes.get(id)
+    }
+
+    pub fn add_edge(&mut self, a: K, b: K, weight: i32) {
+        self.edges.entry(a).or_default().push((b, weight));
+    }
+
+    pub fn neighbours(&self, id: &K) -> impl Iterator<Item = (K, i32)> + '_ {
+        self.edges.get(id).into_iter().flatten().copied()
+    }
+
+    pub fn dijkstra(&self, start: &K, end: &K) -> Option<Vec<K>> {
+        dijkstra(*start, |id| self.neighbours(id), |id| id == end).map(|path| path.nodes)
+    }
+
+    // Cheapest cost between every pair of connected nodes, a node reaches itself for 0
+    pub fn floyd_warshall(&self) -> HashMap<(K, K), i32> {
+        let ids: Vec<K> = self.nodes.keys().copied().collect();
+        let index: HashMap<K, usize> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+        let mut costs = vec![vec![None; ids.len()]; ids.len()];
+        for (i, row) in costs.iter_mut().enumerate() {
+            row[i] = Some(0);
+        }
+        for (a, edges) in &self.edges {
+            for (b, weight) in edges {
+                if let (Some(&i), Some(&j)) = (index.get(a), index.get(b)) {
+                    costs[i][j] = Some(costs[i][j].map_or(*weight, |cost: i32| cost.min(*weight)));
+                }
+            }
+        }
+
+        for k in 0..ids.len() {
+            let from_k = costs[k].clone();
+            for row in costs.iter_mut() {
+                let Some(to_k) = row[k] else {
+                    continue;
+                };
+                for (cost, from_k) in row.iter_mut().zip(&from_k) {
+                    let Some(from_k) = from_k else {
+                        continue;
+                    };
+                    if cost.is_none_or(|cost| to_k + from_k < cost) {
+                        *cost = Some(to_k + from_k);
+                    }
+                }
+            }
+        }
+
+        let mut result = HashMap::new();
+        for (i, row) in costs.iter().enumerate() {
+            for (j, cost) in row.iter().enumerate() {
+                if let Some(cost) = cost {
+                    result.insert((ids[i], ids[j]), *cost);
+                }
+            }
+        }
+        result
+    }
+}
+
+// Weights of the searches below, like i32 or usize
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<K, C> {
+    // from the start to the goal, both included
+    pub nodes: Vec<K>,
+    pub cost: C,
+}
+
+// Walks back from the end through the parent of every node, the start has no parent
+pub fn path_to<K: Eq + Hash + Copy>(parents: &HashMap<K, K>, end: K) -> Vec<K> {
+    let mut path = vec![end];
+    let mut cursor = end;
+    while let Some(parent) = parents.get(&cursor) {
+        path.push(*parent);
+        cursor = *parent;
+    }
+    path.reverse();
+    path
+}
+
+// Heap entry ordered by the lowest priority first
+struct Entry<K, C> {
+    priority: C,
+    cost: C,
+    node: K,
+}
+
+impl<K, C: Ord> PartialEq for Entry<K, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<K, C: Ord> Eq for Entry<K, C> {}
+
+impl<K, C: Ord> PartialOrd for Entry<K, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K, C: Ord> Ord for Entry<K, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority).reverse()
+    }
+}
+
+// The heuristic must never overestimate the cost left to a goal,
+// neighbours give every next node with the cost of the step
+pub fn astar<K, C, N, I>(
+    start: K,
+    mut neighbours: N,
+    mut heuristic: impl FnMut(&K) -> C,
+    mut is_goal: impl FnMut(&K) -> bool,
+) -> Option<Path<K, C>>
+where
+    K: Eq + Hash + Copy,
+    C: Cost,
+    N: FnMut(&K) -> I,
+    I: IntoIterator<Item = (K, C)>,
+{
+    let mut costs = HashMap::from([(start, C::default())]);
+    let mut parents = HashMap::new();
+    let mut heap = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    }]);
+
+    while let Some(Entry { cost, node, .. }) = heap.pop() {
+        // a cheaper way to the node was found after this entry was pushed
+        if costs.get(&node).is_some_and(|best| cost > *best) {
+            continue;
+        }
+        if is_goal(&node) {
+            let nodes = path_to(&parents, node);
+            return Some(Path { nodes, cost });
+        }
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_none_or(|best| next_cost < *best) {
+                costs.insert(next, next_cost);
+                parents.insert(next, node);
+                heap.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    None
+}
+
+pub fn dijkstra<K, C, N, I>(
+    start: K,
+    neighbours: N,
+    is_goal: impl FnMut(&K) -> bool,
+) -> Option<Path<K, C>>
+where
+    K: Eq + Hash + Copy,
+    C: Cost,
+    N: FnMut(&K) -> I,
+    I: IntoIterator<Item = (K, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+// Fewest steps when every step costs the same
+pub fn bfs<K, N, I>(
+    start: K,
+    mut neighbours: N,
+    mut is_goal: impl FnMut(&K) -> bool,
+) -> Option<Vec<K>>
+where
+    K: Eq + Hash + Copy,
+    N: FnMut(&K) -> I,
+    I: IntoIterator<Item = K>,
+{
+    let mut parents = HashMap::new();
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(path_to(&parents, node));
+        }
+        for next in neighbours(&node) {
+            if seen.insert(next) {
+                parents.insert(next, node);
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+// Steps from the start to every reachable node
+pub fn bfs_distances<K, N, I>(start: K, mut neighbours: N) -> HashMap<K, usize>
+where
+    K: Eq + Hash + Copy,
+    N: FnMut(&K) -> I,
+    I: IntoIterator<Item = K>,
+{
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+        for next in neighbours(&node) {
+            if let MapEntry::Vacant(entry) = distances.entry(next) {
+                entry.insert(distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        graph::{astar, bfs, bfs_distances, dijkstra},
+        Graph, Vector,
+    };
+
+    #[test]
+    fn graph_path_finding_works() {
+        let mut graph = Graph::<&str, u32>::new();
+        graph.add_node("A", 0);
+        graph.add_node("B", 0);
+        graph.add_node("C", 0);
+        graph.add_node("D", 0);
+        graph.add_node("E", 0);
+        graph.add_node("F", 0);
+        graph.add_edge("A", "B", 2);
+        graph.add_edge("A", "D", 8);
+        graph.add_edge("B", "D", 5);
+        graph.add_edge("B", "E", 6);
+        graph.add_edge("D", "E", 3);
+        graph.add_edge("D", "F", 2);
+        graph.add_edge("E", "F", 1);
+        graph.add_edge("E", "C", 9);
+        graph.add_edge("F", "C", 3);
+        let route = graph.dijkstra(&"A", &"C");
+        assert_eq!(route, Some(vec!["A", "B", "D", "F", "C"]));
+    }
+
+    #[test]
+    fn graph_path_finding_works_when_end_is_not_the_last_node() {
+        let mut graph = Graph::<&str, u32>::new();
+        graph.add_node("A", 0);
+        graph.add_node("B", 0);
+        graph.add_node("C", 0);
+        graph.add_node("D", 0);
+        graph.add_node("E", 0);
+        graph.add_node("F", 0);
+        graph.add_edge("A", "B", 2);
+        graph.add_edge("A", "D", 8);
+        graph.add_edge("B", "D", 5);
+        graph.add_edge("B", "E", 6);
+        graph.add_edge("D", "E", 3);
+        graph.add_edge("D", "F", 2);
+        graph.add_edge("E", "F", 1);
+        graph.add_edge("E", "C", 9);
+        graph.add_edge("F", "C", 3);
+        let route = graph.dijkstra(&"A", &"E");
+        assert_eq!(route, Some(vec!["A", "B", "E"]));
+    }
+
+    #[test]
+    fn graph_path_finding_works_when_end_is_not_reachable() {
+        let mut graph = Graph::<&str, u32>::new();
+        graph.add_node("A", 0);
+        graph.add_node("B", 0);
+        graph.add_node("C", 0);
+        graph.add_node("D", 0);
+        graph.add_node("E", 0);
+        graph.add_node("F", 0);
+        graph.add_edge("A", "B", 2);
+        graph.add_edge("A", "D", 8);
+        graph.add_edge("B", "D", 5);
+        graph.add_edge("B", "E", 6);
+        graph.add_edge("D", "E", 3);
+        graph.add_edge("D", "F", 2);
+        graph.add_edge("E", "F", 1);
+        let route = graph.dijkstra(&"A", &"C");
+        assert_eq!(route, None);
+    }
+
+    #[test]
+    fn graph_path_finding_works_other_graph() {
+        let mut graph = Graph::<u32, u32>::new();
+        graph.add_node(0, 0);
+        graph.add_node(1, 0);
+        graph.add_node(2, 0);
+        graph.add_node(3, 0);
+        graph.add_node(4, 0);
+        graph.add_node(5, 0);
+        graph.add_node(6, 0);
+        graph.add_node(7, 0);
+        graph.add_edge(0, 3, 1);
+        graph.add_edge(0, 4, 3);
+        graph.add_edge(1, 3, 6);
+        graph.add_edge(2, 1, 7);
+        graph.add_edge(3, 5, 4);
+        graph.add_edge(3, 7, 1);
+        graph.add_edge(4, 2, 4);
+        graph.add_edge(4, 7, 8);
+        graph.add_edge(5, 3, 1);
+        graph.add_edge(5, 6, 8);
+        graph.add_edge(6, 2, 4);
+        graph.add_edge(6, 7, 1);
+        graph.add_edge(7, 6, 8);
+
+        let route = graph.dijkstra(&0, &0);
+        assert_eq!(route, Some(vec![0]));
+
+        let route = graph.dijkstra(&0, &1);
+        assert_eq!(route, Some(vec![0, 4, 2, 1]));
+
+        let route = graph.dijkstra(&0, &2);
+        assert_eq!(route, Some(vec![0, 4, 2]));
+
+        let route = graph.dijkstra(&0, &3);
+        assert_eq!(route, Some(vec![0, 3]));
+
+        let route = graph.dijkstra(&0, &4);
+        assert_eq!(route, Some(vec![0, 4]));
+
+        let route = graph.dijkstra(&0, &5);
+        assert_eq!(route, Some(vec![0, 3, 5]));
+
+        let route = graph.dijkstra(&0, &6);
+        assert_eq!(route, Some(vec![0, 3, 7, 6]));
+
+        let route = graph.dijkstra(&0, &7);
+        assert_eq!(route, Some(vec![0, 3, 7]));
+    }
+
+    // 5x5 room with a wall in the middle column except for the bottom row
+    fn room(position: &Vector) -> Vec<Vector> {
+        [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .into_iter()
+            .map(|(x, y)| *position + Vector::new(x, y))
+            .filter(|p| (0..5).contains(&p.x) && (0..5).contains(&p.y))
+            .filter(|p| p.x != 2 || p.y == 4)
+            .collect()
+    }
+
+    #[test]
+    fn bfs_around_the_wall() {
+        let goal = Vector::new(4, 0);
+        let path = bfs(Vector::zero(), room, |p| *p == goal).unwrap();
+        assert_eq!(path.len() - 1, 12);
+        assert_eq!(path[0], Vector::zero());
+        assert_eq!(path[12], goal);
+
+        let distances = bfs_distances(Vector::zero(), room);
+        assert_eq!(distances.len(), 21);
+        assert_eq!(distances[&goal], 12);
+
+        assert_eq!(bfs(Vector::zero(), room, |p| p.x == 9), None);
+    }
+
+    #[test]
+    fn astar_and_dijkstra_agree() {
+        let goal = Vector::new(4, 0);
+        let steps = |p: &Vector| room(p).into_iter().map(|x| (x, 1)).collect::<Vec<_>>();
+        let heuristic = |p: &Vector| p.manhattan(&goal);
+        let path = astar(Vector::zero(), steps, heuristic, |p| *p == goal).unwrap();
+        assert_eq!(path.cost, 12);
+        assert_eq!(path.nodes.len(), 13);
+
+        let cheapest = dijkstra(Vector::zero(), steps, |p| *p == goal).unwrap();
+        assert_eq!(cheapest.cost, path.cost);
+    }
+
+    #[test]
+    fn graph_floyd_warshall() {
+        let mut graph = Graph::<char, ()>::new();
+        for id in ['A', 'B', 'C', 'D'] {
+            graph.add_node(id, ());
+        }
+        graph.add_edge('A', 'B', 4);
+        graph.add_edge('A', 'C', 1);
+        graph.add_edge('C', 'B', 2);
+        graph.add_edge('B', 'D', 1);
+
+        let costs = graph.floyd_warshall();
+        assert_eq!(costs[&('A', 'D')], 4);
+        assert_eq!(costs[&('A', 'B')], 3);
+        assert_eq!(costs[&('C', 'C')], 0);
+        assert_eq!(costs.get(&('D', 'A')), None);
+        // every node to itself, A to B, C and D, C to B and D, B to D
+        assert_eq!(costs.len(), 4 + 3 + 2 + 1);
+    }
+}
@@ -0,0 +1,505 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    iter::successors,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    parse::{numbered_lines, ParseError, ParseResult},
+    Vector,
+};
+
+// y grows downwards like the lines of the input
+pub const UP: Vector = Vector { x: 0, y: -1 };
+pub const RIGHT: Vector = Vector { x: 1, y: 0 };
+pub const DOWN: Vector = Vector { x: 0, y: 1 };
+pub const LEFT: Vector = Vector { x: -1, y: 0 };
+
+// Clockwise starting at the top
+pub const NEIGHBOURS_4: [Vector; 4] = [UP, RIGHT, DOWN, LEFT];
+pub const NEIGHBOURS_8: [Vector; 8] = [
+    UP,
+    Vector { x: 1, y: -1 },
+    RIGHT,
+    Vector { x: 1, y: 1 },
+    DOWN,
+    Vector { x: -1, y: 1 },
+    LEFT,
+    Vector { x: -1, y: -1 },
+];
+
+fn inside(bounds: Option<(Vector, Vector)>, position: Vector) -> bool {
+    match bounds {
+        Some((min, max)) => {
+            (min.x..=max.x).contains(&position.x) && (min.y..=max.y).contains(&position.y)
+        }
+        None => false,
+    }
+}
+
+// Read access shared by the dense Grid and the HashMap backed SparseGrid
+pub trait Cells<T> {
+    fn cell(&self, position: Vector) -> Option<&T>;
+
+    // Top left and bottom right corner of the area holding cells, None without any cell
+    fn bounds(&self) -> Option<(Vector, Vector)>;
+
+    fn in_bounds(&self, position: Vector) -> bool {
+        inside(self.bounds(), position)
+    }
+
+    fn neighbours4<'a>(&'a self, position: Vector) -> impl Iterator<Item = (Vector, &'a T)>
+    where
+        T: 'a,
+    {
+        NEIGHBOURS_4.into_iter().filter_map(move |step| {
+            let next = position + step;
+            self.cell(next).map(|cell| (next, cell))
+        })
+    }
+
+    fn neighbours8<'a>(&'a self, position: Vector) -> impl Iterator<Item = (Vector, &'a T)>
+    where
+        T: 'a,
+    {
+        NEIGHBOURS_8.into_iter().filter_map(move |step| {
+            let next = position + step;
+            self.cell(next).map(|cell| (next, cell))
+        })
+    }
+
+    // Cells met walking from the position (itself excluded) in steps of the direction
+    // until leaving the bounds, empty places of a sparse grid are skipped
+    fn ray<'a>(&'a self, from: Vector, direction: Vector) -> impl Iterator<Item = (Vector, &'a T)>
+    where
+        T: 'a,
+    {
+        let bounds = self.bounds();
+        successors(Some(from + direction), move |position| {
+            Some(*position + direction)
+        })
+        .take_while(move |position| direction != Vector::zero() && inside(bounds, *position))
+        .filter_map(move |position| self.cell(position).map(|cell| (position, cell)))
+    }
+
+    // One line per row of the bounds, empty places are drawn with the given char
+    fn render(&self, empty: char) -> String
+    where
+        T: Display,
+    {
+        let Some((min, max)) = self.bounds() else {
+            return String::new();
+        };
+        (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| match self.cell(Vector::new(x, y)) {
+                        Some(cell) => cell.to_string(),
+                        None => empty.to_string(),
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn unexpected(number: usize, column: usize, expected: &str, c: char) -> ParseError {
+    ParseError::new(number, column, expected, &format!("\"{}\"", c))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    // Cells are created row by row
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Vector) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Vector::new(x as isize, y as isize)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // Every character of the map becomes a cell, all rows have to be equally long
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> ParseResult<Grid<T>> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for (number, line) in numbered_lines(input) {
+            let mut length = 0;
+            for (index, c) in line.chars().enumerate() {
+                if width.is_some_and(|width| index == width) {
+                    let expected = format!("a row of {} cells", index);
+                    return Err(unexpected(number, index + 1, &expected, c));
+                }
+                cells.push(cell(c).ok_or_else(|| unexpected(number, index + 1, expected, c))?);
+                length += 1;
+            }
+            match width {
+                Some(width) if length < width => {
+                    let expected = format!("a row of {} cells", width);
+                    return Err(ParseError::new(
+                        number,
+                        length + 1,
+                        &expected,
+                        "end of line",
+                    ));
+                }
+                Some(_) => {}
+                None => width = Some(length),
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, position: Vector) -> Option<usize> {
+        let (x, y) = (position.x, position.y);
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+        Some(y as usize * self.width + x as usize)
+    }
+
+    pub fn get(&self, position: Vector) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Vector) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    // Row by row from the top left corner
+    pub fn positions(&self) -> impl Iterator<Item = Vector> {
+        let width = self.width;
+        (0..self.cells.len())
+            .map(move |index| Vector::new((index % width) as isize, (index / width) as isize))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Vector, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Vector> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    // Rows become columns
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Vector::new(p.y, p.x)].clone()
+        })
+    }
+
+    // A quarter turn clockwise, the top row becomes the right column
+    pub fn rotate_right(&self) -> Grid<T> {
+        let last = self.height as isize - 1;
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Vector::new(p.y, last - p.x)].clone()
+        })
+    }
+
+    // A quarter turn counterclockwise, the top row becomes the left column
+    pub fn rotate_left(&self) -> Grid<T> {
+        let last = self.width as isize - 1;
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Vector::new(last - p.y, p.x)].clone()
+        })
+    }
+}
+
+impl<T> Cells<T> for Grid<T> {
+    fn cell(&self, position: Vector) -> Option<&T> {
+        self.get(position)
+    }
+
+    fn bounds(&self) -> Option<(Vector, Vector)> {
+        if self.cells.is_empty() {
+            return None;
+        }
+        let max = Vector::new(self.width as isize - 1, self.height as isize - 1);
+        Some((Vector::zero(), max))
+    }
+}
+
+impl<T> Index<Vector> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Vector) -> &Self::Output {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+impl<T> IndexMut<Vector> for Grid<T> {
+    fn index_mut(&mut self, position: Vector) -> &mut Self::Output {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(' '))
+    }
+}
+
+// Only the occupied places are stored, positions may be negative
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Vector, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+
+    // Like Grid::parse, but the empty character leaves no cell and rows may differ in length
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        empty: char,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> ParseResult<SparseGrid<T>> {
+        let mut grid = SparseGrid::new();
+        for (number, line) in numbered_lines(input) {
+            for (index, c) in line.chars().enumerate() {
+                if c == empty {
+                    continue;
+                }
+                let value = cell(c).ok_or_else(|| unexpected(number, index + 1, expected, c))?;
+                let position = Vector::new(index as isize, number as isize - 1);
+                grid.insert(position, value);
+            }
+        }
+        Ok(grid)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, position: Vector) -> Option<&T> {
+        self.cells.get(&position)
+    }
+
+    pub fn get_mut(&mut self, position: Vector) -> Option<&mut T> {
+        self.cells.get_mut(&position)
+    }
+
+    pub fn contains(&self, position: Vector) -> bool {
+        self.cells.contains_key(&position)
+    }
+
+    pub fn insert(&mut self, position: Vector, value: T) -> Option<T> {
+        self.cells.insert(position, value)
+    }
+
+    pub fn remove(&mut self, position: Vector) -> Option<T> {
+        self.cells.remove(&position)
+    }
+
+    // In no particular order
+    pub fn positions(&self) -> impl Iterator<Item = Vector> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Vector, &T)> {
+        self.cells.iter().map(|(position, cell)| (*position, cell))
+    }
+}
+
+impl<T> Cells<T> for SparseGrid<T> {
+    fn cell(&self, position: Vector) -> Option<&T> {
+        self.get(position)
+    }
+
+    fn bounds(&self) -> Option<(Vector, Vector)> {
+        self.cells.keys().fold(None, |bounds, p| match bounds {
+            None => Some((*p, *p)),
+            Some((min, max)) => Some((
+                Vector::new(min.x.min(p.x), min.y.min(p.y)),
+                Vector::new(max.x.max(p.x), max.y.max(p.y)),
+            )),
+        })
+    }
+}
+
+impl<T> FromIterator<(Vector, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Vector, T)>>(iter: I) -> Self {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render('.'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        grid::{Cells, Grid, SparseGrid, DOWN, LEFT, RIGHT, UP},
+        ParseError, Vector,
+    };
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn grid_parse_and_render() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Vector::new(2, 1)], 6);
+        assert_eq!(grid.get(Vector::new(3, 0)), None);
+        assert_eq!(grid.find(|x| *x == 5), Some(Vector::new(1, 1)));
+        assert_eq!(grid.to_string(), "123\n456");
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2, 3], &[4, 5, 6]]
+        );
+    }
+
+    #[test]
+    fn grid_parse_errors() {
+        let parse = |input| Grid::parse(input, "a digit", |c| c.to_digit(10));
+        assert_eq!(
+            parse("12\n3x"),
+            Err(ParseError::new(2, 2, "a digit", "\"x\""))
+        );
+        assert_eq!(
+            parse("12\n345"),
+            Err(ParseError::new(2, 3, "a row of 2 cells", "\"5\""))
+        );
+        assert_eq!(
+            parse("12\n3"),
+            Err(ParseError::new(2, 2, "a row of 2 cells", "end of line"))
+        );
+    }
+
+    #[test]
+    fn grid_neighbours() {
+        let grid = digits("123\n456\n789");
+        let sides: Vec<_> = grid.neighbours4(Vector::new(0, 0)).map(|x| *x.1).collect();
+        assert_eq!(sides, vec![2, 4]);
+        let around: Vec<_> = grid.neighbours8(Vector::new(1, 1)).map(|x| *x.1).collect();
+        assert_eq!(around, vec![2, 3, 6, 9, 8, 7, 4, 1]);
+    }
+
+    #[test]
+    fn grid_ray() {
+        let grid = digits("123\n456\n789");
+        let ray = |direction| -> Vec<u32> {
+            grid.ray(Vector::new(1, 1), direction)
+                .map(|x| *x.1)
+                .collect()
+        };
+        assert_eq!(ray(UP), vec![2]);
+        assert_eq!(ray(RIGHT), vec![6]);
+        assert_eq!(ray(Vector::new(-1, 1)), vec![7]);
+        assert_eq!(
+            grid.ray(Vector::new(0, 0), DOWN)
+                .map(|x| x.0)
+                .collect::<Vec<_>>(),
+            vec![Vector::new(0, 1), Vector::new(0, 2)]
+        );
+        assert_eq!(grid.ray(Vector::new(0, 0), LEFT).count(), 0);
+    }
+
+    #[test]
+    fn grid_transpose_and_rotate() {
+        let grid = digits("123\n456");
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.rotate_right().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_left().to_string(), "36\n25\n14");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+    }
+
+    #[test]
+    fn sparse_grid_shares_cells() {
+        let mut grid =
+            SparseGrid::parse("..#\n#..\n", "\"#\"", '.', |c| (c == '#').then_some('#')).unwrap();
+        assert_eq!(grid.len(), 2);
+        grid.insert(Vector::new(-1, 0), '#');
+        assert_eq!(grid.bounds(), Some((Vector::new(-1, 0), Vector::new(2, 1))));
+        assert_eq!(grid.to_string(), "#..#\n.#..");
+        assert_eq!(grid.neighbours8(Vector::new(0, 0)).count(), 2);
+        assert_eq!(
+            grid.ray(Vector::new(-1, 0), RIGHT)
+                .map(|x| x.0)
+                .collect::<Vec<_>>(),
+            vec![Vector::new(2, 0)]
+        );
+        assert!(SparseGrid::<char>::new().bounds().is_none());
+    }
+}
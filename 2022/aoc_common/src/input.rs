@@ -0,0 +1,11 @@
+use std::io::{self, Read};
+
+// Read whole stdin into a string
+// Panics on IO error the same way every day did it before
+pub fn read_input() -> String {
+    let mut input = String::new();
+    if let Err(error) = io::stdin().read_to_string(&mut input) {
+        panic!("{}", error);
+    }
+    input
+}
@@ -0,0 +1,190 @@
+use std::ops::Range;
+
+// A set of integers kept as sorted half open ranges that neither overlap nor
+// touch, so every set has exactly one representation
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // Number of integers in the set
+    pub fn total_length(&self) -> i64 {
+        self.ranges
+            .iter()
+            .map(|range| range.end - range.start)
+            .sum()
+    }
+
+    pub fn ranges(&self) -> &[Range<i64>] {
+        &self.ranges
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        // the first range that ends after the value is the only candidate
+        let index = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.start <= value)
+    }
+
+    pub fn insert(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+        // ranges that overlap or touch the new one are merged into it
+        let first = self.ranges.partition_point(|x| x.end < range.start);
+        let last = self.ranges.partition_point(|x| x.start <= range.end);
+        let mut merged = range;
+        if first < last {
+            merged.start = merged.start.min(self.ranges[first].start);
+            merged.end = merged.end.max(self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn remove(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|x| x.end <= range.start);
+        let last = self.ranges.partition_point(|x| x.start < range.end);
+        if first == last {
+            return;
+        }
+        // only the ends of the outer ranges stick out of the removed range
+        let before = self.ranges[first].start..range.start;
+        let after = range.end..self.ranges[last - 1].end;
+        let rest = [before, after].into_iter().filter(|x| !x.is_empty());
+        self.ranges.splice(first..last, rest);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut set = self.clone();
+        for range in &other.ranges {
+            set.insert(range.clone());
+        }
+        set
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let range = a.start.max(b.start)..a.end.min(b.end);
+            if !range.is_empty() {
+                ranges.push(range);
+            }
+            // the range that ends first can't meet any later one
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut set = self.clone();
+        for range in &other.ranges {
+            set.remove(range.clone());
+        }
+        set
+    }
+
+    // The integers of a range that are not in the set
+    pub fn gaps(&self, within: Range<i64>) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        set.insert(within);
+        set.difference(self)
+    }
+}
+
+impl FromIterator<Range<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(iter: I) -> Self {
+        let mut ranges: Vec<Range<i64>> = iter.into_iter().filter(|x| !x.is_empty()).collect();
+        ranges.sort_by_key(|range| range.start);
+        let mut merged: Vec<Range<i64>> = vec![];
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        IntervalSet { ranges: merged }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interval::IntervalSet;
+
+    fn set(ranges: &[(i64, i64)]) -> IntervalSet {
+        ranges.iter().map(|(start, end)| *start..*end).collect()
+    }
+
+    #[test]
+    fn ranges_merge() {
+        let mut intervals = set(&[(5, 8), (0, 2), (1, 3), (8, 9), (4, 4)]);
+        assert_eq!(intervals.ranges(), &[0..3, 5..9]);
+        assert_eq!(intervals.total_length(), 7);
+        intervals.insert(3..5);
+        assert_eq!(intervals.ranges().to_vec(), vec![0..9]);
+        intervals.remove(2..4);
+        intervals.remove(8..20);
+        assert_eq!(intervals.ranges(), &[0..2, 4..8]);
+        assert!(intervals.contains(0) && intervals.contains(7));
+        assert!(!intervals.contains(2) && !intervals.contains(8) && !intervals.contains(-1));
+        assert!(IntervalSet::new().is_empty());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (28, 40)]);
+        assert_eq!(a.union(&b).ranges().to_vec(), vec![0..40]);
+        assert_eq!(a.intersection(&b).ranges(), &[5..10, 20..25, 28..30]);
+        assert_eq!(a.difference(&b).ranges(), &[0..5, 25..28]);
+        assert_eq!(b.difference(&a).ranges(), &[10..20, 30..40]);
+        assert_eq!(a.gaps(-5..35).ranges(), &[-5..0, 10..20, 30..35]);
+        assert!(a.gaps(2..8).is_empty());
+    }
+
+    #[test]
+    fn same_as_a_plain_set() {
+        // pseudo random ranges inside 0..100
+        let ranges: Vec<_> = (0..40_i64)
+            .map(|i| {
+                let start = i * 37 % 100;
+                start..(start + i * 13 % 9).min(100)
+            })
+            .collect();
+        let (left, right) = ranges.split_at(20);
+        let a: IntervalSet = left.iter().cloned().collect();
+        let b: IntervalSet = right.iter().cloned().collect();
+        let in_a = |x: i64| left.iter().any(|range| range.contains(&x));
+        let in_b = |x: i64| right.iter().any(|range| range.contains(&x));
+
+        let union = a.union(&b);
+        let intersection = a.intersection(&b);
+        let difference = a.difference(&b);
+        for x in -1..=101 {
+            assert_eq!(a.contains(x), in_a(x), "{}", x);
+            assert_eq!(union.contains(x), in_a(x) || in_b(x), "{}", x);
+            assert_eq!(intersection.contains(x), in_a(x) && in_b(x), "{}", x);
+            assert_eq!(difference.contains(x), in_a(x) && !in_b(x), "{}", x);
+        }
+        let count = (0..100).filter(|x| in_a(*x)).count() as i64;
+        assert_eq!(a.total_length(), count);
+    }
+}
@@ -0,0 +1,27 @@
+pub mod cycle;
+pub mod diamond;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod numeral;
+pub mod packet;
+pub mod parse;
+pub mod search;
+pub mod solution;
+pub mod tree;
+pub mod vector;
+
+pub use cycle::{brent, find_cycle, Cycle, CycleFinder};
+pub use diamond::{Diamond, Line, Rect};
+pub use graph::Graph;
+pub use grid::{Cells, Grid, SparseGrid};
+pub use input::read_input;
+pub use interval::IntervalSet;
+pub use numeral::{Numeral, NumeralError};
+pub use packet::{Order, Packet, ValueError};
+pub use parse::{numbered_lines, Cursor, ParseError, ParseResult};
+pub use search::{search, Mode, Problem};
+pub use solution::{Answer, Solution};
+pub use tree::{NodeId, PathError, Tree};
+pub use vector::Vector;
@@ -0,0 +1,231 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumeralError {
+    // byte offset of the first character that isn't a digit
+    InvalidDigit(usize),
+    OutOfRange,
+    // the number of symbols of a system, at least 2 and at most 36 for
+    // the standard ones
+    InvalidBase(usize),
+    // digit values that neither include zero nor start at one
+    InvalidDigits,
+}
+
+impl Display for NumeralError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NumeralError::InvalidDigit(offset) => write!(f, "invalid digit at {}", offset),
+            NumeralError::OutOfRange => write!(f, "number out of range"),
+            NumeralError::InvalidBase(base) => write!(f, "no numeral system of base {}", base),
+            NumeralError::InvalidDigits => write!(f, "digits that aren't around zero or from one"),
+        }
+    }
+}
+
+impl Error for NumeralError {}
+
+const ALPHANUMERIC: &str = "0123456789abcdefghijklmnopqrstuvwxyz";
+
+// A positional numeral system, the symbols stand for consecutive digit values
+// starting at `lowest`. Standard systems start at 0, balanced ones like SNAFU
+// with "=-012" at minus half of the base and bijective ones like spreadsheet
+// columns with "ABC..Z" at 1, where zero is the empty string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Numeral {
+    symbols: Vec<char>,
+    lowest: i64,
+}
+
+impl Numeral {
+    pub fn new(symbols: &str, lowest: i64) -> Result<Numeral, NumeralError> {
+        let symbols: Vec<char> = symbols.chars().collect();
+        if symbols.len() < 2 {
+            return Err(NumeralError::InvalidBase(symbols.len()));
+        }
+        // digits without a zero only terminate when they start at one
+        let highest = lowest.saturating_add(symbols.len() as i64 - 1);
+        if lowest > 1 || highest < 0 {
+            return Err(NumeralError::InvalidDigits);
+        }
+        Ok(Numeral { symbols, lowest })
+    }
+
+    // Digits and then lowercase letters, up to base 36
+    pub fn standard(base: usize) -> Result<Numeral, NumeralError> {
+        match ALPHANUMERIC.get(..base) {
+            Some(symbols) => Numeral::new(symbols, 0),
+            None => Err(NumeralError::InvalidBase(base)),
+        }
+    }
+
+    // An even number of symbols has no digit in the middle for zero
+    pub fn balanced(symbols: &str) -> Result<Numeral, NumeralError> {
+        let base = symbols.chars().count() as i64;
+        if base % 2 == 0 {
+            return Err(NumeralError::InvalidDigits);
+        }
+        Numeral::new(symbols, -(base - 1) / 2)
+    }
+
+    pub fn bijective(symbols: &str) -> Result<Numeral, NumeralError> {
+        Numeral::new(symbols, 1)
+    }
+
+    pub fn base(&self) -> i64 {
+        self.symbols.len() as i64
+    }
+
+    pub fn value(&self, symbol: char) -> Option<i64> {
+        let index = self.symbols.iter().position(|x| *x == symbol)?;
+        Some(self.lowest + index as i64)
+    }
+
+    pub fn symbol(&self, digit: i64) -> Option<char> {
+        let index = usize::try_from(digit - self.lowest).ok()?;
+        self.symbols.get(index).copied()
+    }
+
+    // Digit values of a number, the most significant first. None when the
+    // digits can't reach the sign of the value
+    pub fn digits(&self, value: i128) -> Option<Vec<i64>> {
+        let highest = self.lowest + self.base() - 1;
+        if (value < 0 && self.lowest >= 0) || (value > 0 && highest <= 0) {
+            return None;
+        }
+        if value == 0 && self.lowest <= 0 {
+            return Some(vec![0]);
+        }
+
+        let base = self.base() as i128;
+        let lowest = self.lowest as i128;
+        let mut digits = vec![];
+        let mut remainder = value;
+        while remainder != 0 {
+            let digit = (remainder - lowest).rem_euclid(base) + lowest;
+            digits.push(digit as i64);
+            remainder = (remainder - digit) / base;
+        }
+        digits.reverse();
+        Some(digits)
+    }
+
+    pub fn format(&self, value: i128) -> Option<String> {
+        let digits = self.digits(value)?;
+        digits.into_iter().map(|digit| self.symbol(digit)).collect()
+    }
+
+    pub fn parse(&self, text: &str) -> Result<i128, NumeralError> {
+        let mut value: i128 = 0;
+        for (offset, symbol) in text.char_indices() {
+            let digit = self
+                .value(symbol)
+                .ok_or(NumeralError::InvalidDigit(offset))?;
+            value = value
+                .checked_mul(self.base() as i128)
+                .and_then(|value| value.checked_add(digit as i128))
+                .ok_or(NumeralError::OutOfRange)?;
+        }
+        Ok(value)
+    }
+
+    // Writes a number of this system in another one
+    pub fn convert(&self, text: &str, to: &Numeral) -> Result<String, NumeralError> {
+        to.format(self.parse(text)?).ok_or(NumeralError::OutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::numeral::{Numeral, NumeralError};
+
+    #[test]
+    fn standard_bases() {
+        let binary = Numeral::standard(2).unwrap();
+        let hex = Numeral::standard(16).unwrap();
+        assert_eq!(binary.format(10), Some("1010".to_string()));
+        assert_eq!(hex.format(255), Some("ff".to_string()));
+        assert_eq!(hex.format(0), Some("0".to_string()));
+        assert_eq!(hex.format(-1), None);
+        assert_eq!(hex.parse("7f"), Ok(127));
+        assert_eq!(hex.parse("7g"), Err(NumeralError::InvalidDigit(1)));
+        assert_eq!(binary.convert("11111111", &hex), Ok("ff".to_string()));
+        assert_eq!(hex.parse(&"f".repeat(40)), Err(NumeralError::OutOfRange));
+    }
+
+    #[test]
+    fn systems_that_dont_exist() {
+        assert!(Numeral::standard(36).is_ok());
+        assert_eq!(Numeral::standard(37), Err(NumeralError::InvalidBase(37)));
+        assert_eq!(Numeral::standard(1), Err(NumeralError::InvalidBase(1)));
+        assert_eq!(Numeral::standard(0), Err(NumeralError::InvalidBase(0)));
+        assert_eq!(Numeral::bijective("A"), Err(NumeralError::InvalidBase(1)));
+        assert_eq!(Numeral::balanced("-+"), Err(NumeralError::InvalidDigits));
+        assert_eq!(Numeral::new("ab", 2), Err(NumeralError::InvalidDigits));
+        assert_eq!(Numeral::new("ab", -2), Err(NumeralError::InvalidDigits));
+        assert_eq!(
+            NumeralError::InvalidBase(37).to_string(),
+            "no numeral system of base 37"
+        );
+    }
+
+    #[test]
+    fn balanced_digits() {
+        let ternary = Numeral::balanced("-0+").unwrap();
+        assert_eq!(ternary.format(2), Some("+-".to_string()));
+        assert_eq!(ternary.format(-2), Some("-+".to_string()));
+        assert_eq!(ternary.format(0), Some("0".to_string()));
+        assert_eq!(ternary.digits(8), Some(vec![1, 0, -1]));
+
+        let snafu = Numeral::balanced("=-012").unwrap();
+        assert_eq!(snafu.format(1747), Some("1=-0-2".to_string()));
+        assert_eq!(snafu.parse("2=-01"), Ok(976));
+        assert_eq!(
+            snafu.convert("1=-0-2", &ternary),
+            Ok("+-++--0+".to_string())
+        );
+    }
+
+    #[test]
+    fn bijective_digits() {
+        let columns = Numeral::bijective("ABCDEFGHIJKLMNOPQRSTUVWXYZ").unwrap();
+        assert_eq!(columns.format(1), Some("A".to_string()));
+        assert_eq!(columns.format(26), Some("Z".to_string()));
+        assert_eq!(columns.format(27), Some("AA".to_string()));
+        assert_eq!(columns.format(702), Some("ZZ".to_string()));
+        assert_eq!(columns.format(703), Some("AAA".to_string()));
+        assert_eq!(columns.format(0), Some(String::new()));
+        assert_eq!(columns.format(-3), None);
+        assert_eq!(columns.parse("AZ"), Ok(52));
+        assert_eq!(columns.parse(""), Ok(0));
+    }
+
+    #[test]
+    fn every_system_round_trips() {
+        let systems = [
+            Numeral::standard(2).unwrap(),
+            Numeral::standard(10).unwrap(),
+            Numeral::standard(36).unwrap(),
+            Numeral::balanced("-0+").unwrap(),
+            Numeral::balanced("=-012").unwrap(),
+            Numeral::bijective("12").unwrap(),
+            Numeral::new("ab", 0).unwrap(),
+            Numeral::new("xyz", -1).unwrap(),
+        ];
+        for system in &systems {
+            for value in -500..500 {
+                if let Some(text) = system.format(value) {
+                    assert_eq!(system.parse(&text), Ok(value), "{:?} {}", system, text);
+                    for other in &systems {
+                        if let Ok(converted) = system.convert(&text, other) {
+                            assert_eq!(other.parse(&converted), Ok(value));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use crate::parse::{Cursor, ParseError, ParseResult};
+use serde_json::{Number, Value};
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+// A number or a list of packets, written like [1,[2,[]]]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Packet {
+    Integer(i64),
+    List(Vec<Packet>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    // a number next to a list is compared as a list holding only that number
+    Packet,
+    // every number comes before every list, lists compare item by item
+    Lexicographic,
+}
+
+fn spaces(cursor: &mut Cursor) {
+    while cursor.tag(" ").is_ok() {}
+}
+
+fn parse_packet(cursor: &mut Cursor) -> ParseResult<Packet> {
+    if cursor.tag("[").is_err() {
+        let value = cursor
+            .number::<i64>()
+            .map_err(|_| cursor.error("a number or \"[\""))?;
+        return Ok(Packet::Integer(value));
+    }
+
+    let mut list = vec![];
+    spaces(cursor);
+    if cursor.tag("]").is_ok() {
+        return Ok(Packet::List(list));
+    }
+    loop {
+        spaces(cursor);
+        list.push(parse_packet(cursor)?);
+        spaces(cursor);
+        if cursor.tag("]").is_ok() {
+            return Ok(Packet::List(list));
+        }
+        cursor
+            .tag(",")
+            .map_err(|_| cursor.error("\",\" or \"]\""))?;
+    }
+}
+
+impl Packet {
+    // A whole line holding one packet, spaces between the tokens are skipped
+    pub fn parse(line: usize, text: &str) -> ParseResult<Packet> {
+        let mut cursor = Cursor::new(line, text);
+        spaces(&mut cursor);
+        let packet = parse_packet(&mut cursor)?;
+        spaces(&mut cursor);
+        cursor.end()?;
+        Ok(packet)
+    }
+
+    pub fn compare(&self, other: &Packet, order: Order) -> Ordering {
+        match (self, other) {
+            (Packet::Integer(a), Packet::Integer(b)) => a.cmp(b),
+            (Packet::Integer(_), Packet::List(_)) if order == Order::Lexicographic => {
+                Ordering::Less
+            }
+            (Packet::List(_), Packet::Integer(_)) if order == Order::Lexicographic => {
+                Ordering::Greater
+            }
+            (Packet::Integer(value), Packet::List(b)) => {
+                compare_lists(&[Packet::Integer(*value)], b, order)
+            }
+            (Packet::List(a), Packet::Integer(value)) => {
+                compare_lists(a, &[Packet::Integer(*value)], order)
+            }
+            (Packet::List(a), Packet::List(b)) => compare_lists(a, b, order),
+        }
+    }
+}
+
+fn compare_lists(a: &[Packet], b: &[Packet], order: Order) -> Ordering {
+    for (a, b) in a.iter().zip(b) {
+        match a.compare(b, order) {
+            Ordering::Equal => continue,
+            other => return other,
+        }
+    }
+    // the list that runs out of items first is the smaller one
+    a.len().cmp(&b.len())
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Packet::parse(1, text)
+    }
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Integer(value) => write!(f, "{}", value),
+            Packet::List(list) => {
+                write!(f, "[")?;
+                for (i, packet) in list.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", packet)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+// A JSON value that isn't a whole number or an array of packets, the path
+// leads to it from the top like [2][0]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueError {
+    pub path: String,
+    pub found: String,
+}
+
+impl Display for ValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "the top"
+        } else {
+            &self.path
+        };
+        write!(
+            f,
+            "expected an integer or an array at {}, found {}",
+            path, self.found
+        )
+    }
+}
+
+impl Error for ValueError {}
+
+fn from_value(value: &Value, path: &mut String) -> Result<Packet, ValueError> {
+    match value {
+        Value::Number(n) if n.is_i64() => Ok(Packet::Integer(n.as_i64().unwrap())),
+        Value::Array(values) => {
+            let mut list = vec![];
+            for (i, value) in values.iter().enumerate() {
+                let len = path.len();
+                path.push_str(&format!("[{}]", i));
+                list.push(from_value(value, path)?);
+                path.truncate(len);
+            }
+            Ok(Packet::List(list))
+        }
+        _ => Err(ValueError {
+            path: path.clone(),
+            found: value.to_string(),
+        }),
+    }
+}
+
+impl TryFrom<&Value> for Packet {
+    type Error = ValueError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        from_value(value, &mut String::new())
+    }
+}
+
+impl From<&Packet> for Value {
+    fn from(packet: &Packet) -> Self {
+        match packet {
+            Packet::Integer(value) => Value::Number(Number::from(*value)),
+            Packet::List(list) => Value::Array(list.iter().map(Value::from).collect()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        packet::{Order, Packet, ValueError},
+        parse::ParseError,
+    };
+    use serde_json::{json, Value};
+    use std::cmp::Ordering;
+
+    fn packet(text: &str) -> Packet {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_nested_lists() {
+        use Packet::{Integer, List};
+        assert_eq!(packet("[]"), List(vec![]));
+        assert_eq!(
+            packet("[1,[],[[2],[3,-4]],5]"),
+            List(vec![
+                Integer(1),
+                List(vec![]),
+                List(vec![
+                    List(vec![Integer(2)]),
+                    List(vec![Integer(3), Integer(-4)])
+                ]),
+                Integer(5),
+            ])
+        );
+        assert_eq!(packet(" [ 1 , [ ] ] "), packet("[1,[]]"));
+        assert_eq!(packet("7"), Integer(7));
+        assert_eq!(packet("[12345678901]"), List(vec![Integer(12345678901)]));
+        for text in ["[]", "[1,[2,[3,[4,[5,6,7]]]],8,9]", "[[[]],-1]"] {
+            assert_eq!(packet(text).to_string(), text);
+        }
+    }
+
+    #[test]
+    fn parse_reports_the_column() {
+        assert_eq!(
+            Packet::parse(3, "[1,x]"),
+            Err(ParseError::new(3, 4, "a number or \"[\"", "\"x]\""))
+        );
+        assert_eq!(
+            Packet::parse(3, "[1,2"),
+            Err(ParseError::new(3, 5, "\",\" or \"]\"", "end of line"))
+        );
+        assert_eq!(
+            "[1]]".parse::<Packet>(),
+            Err(ParseError::new(1, 4, "end of line", "\"]\""))
+        );
+        assert!("[1 2]".parse::<Packet>().is_err());
+        assert!("".parse::<Packet>().is_err());
+    }
+
+    #[test]
+    fn packet_and_lexicographic_order() {
+        let compare = |a: &str, b: &str, order| packet(a).compare(&packet(b), order);
+        let packet_order = |a, b| compare(a, b, Order::Packet);
+        assert_eq!(packet_order("[1,1,3,1,1]", "[1,1,5,1,1]"), Ordering::Less);
+        assert_eq!(packet_order("[[1],[2,3,4]]", "[[1],4]"), Ordering::Less);
+        assert_eq!(packet_order("[9]", "[[8,7,6]]"), Ordering::Greater);
+        assert_eq!(packet_order("[[4,4],4,4]", "[[4,4],4,4,4]"), Ordering::Less);
+        assert_eq!(packet_order("[[[]]]", "[[]]"), Ordering::Greater);
+        // equal in the puzzle order without being the same packet
+        assert_eq!(packet_order("[1]", "[[1]]"), Ordering::Equal);
+        assert_ne!(packet("[1]"), packet("[[1]]"));
+
+        assert_eq!(
+            compare("[1]", "[[1]]", Order::Lexicographic),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare("[9]", "[[8,7,6]]", Order::Lexicographic),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare("[[1],4]", "[[1],[2,3,4]]", Order::Lexicographic),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare("[1,2]", "[1,2,0]", Order::Lexicographic),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn json_values() {
+        let value = json!([1, [], [[2], [3, -4]]]);
+        let packet = Packet::try_from(&value).unwrap();
+        assert_eq!(packet.to_string(), "[1,[],[[2],[3,-4]]]");
+        assert_eq!(Value::from(&packet), value);
+
+        assert_eq!(
+            Packet::try_from(&json!([1, [2, "three"]])),
+            Err(ValueError {
+                path: "[1][1]".to_string(),
+                found: "\"three\"".to_string()
+            })
+        );
+        let error = Packet::try_from(&json!(1.5)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected an integer or an array at the top, found 1.5"
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+// Points at the place in the input where parsing stopped, line and column start at 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: &str, found: &str) -> ParseError {
+        ParseError {
+            line,
+            column,
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }
+    }
+
+    // The input stopped before something it needs, like the empty line between two blocks
+    pub fn end_of_input(line: usize, expected: &str) -> ParseError {
+        ParseError::new(line, 1, expected, "end of input")
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+// Reads tokens of a single line from left to right and reports
+// the column of the first token that does not fit
+#[derive(Debug, Clone)]
+pub struct Cursor<'a> {
+    line: usize,
+    text: &'a str,
+    offset: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(line: usize, text: &'a str) -> Cursor<'a> {
+        Cursor {
+            line,
+            text,
+            offset: 0,
+        }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.offset..]
+    }
+
+    pub fn is_end(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    pub fn column(&self) -> usize {
+        self.text[..self.offset].chars().count() + 1
+    }
+
+    pub fn error(&self, expected: &str) -> ParseError {
+        let rest = self.rest();
+        let found = match rest.chars().next() {
+            None => "end of line".to_string(),
+            Some(c) if c.is_whitespace() => format!("{:?}", c),
+            Some(_) => format!("\"{}\"", rest.split_whitespace().next().unwrap()),
+        };
+        ParseError::new(self.line, self.column(), expected, &found)
+    }
+
+    fn take(&mut self, len: usize) -> &'a str {
+        let value = &self.rest()[..len];
+        self.offset += len;
+        value
+    }
+
+    pub fn tag(&mut self, tag: &str) -> ParseResult<()> {
+        if self.rest().starts_with(tag) {
+            self.take(tag.len());
+            Ok(())
+        } else {
+            Err(self.error(&format!("\"{}\"", tag)))
+        }
+    }
+
+    pub fn char(&mut self) -> ParseResult<char> {
+        match self.rest().chars().next() {
+            Some(c) => {
+                self.take(c.len_utf8());
+                Ok(c)
+            }
+            None => Err(self.error("a character")),
+        }
+    }
+
+    pub fn digit(&mut self) -> ParseResult<u32> {
+        match self.rest().chars().next().and_then(|c| c.to_digit(10)) {
+            Some(digit) => {
+                self.take(1);
+                Ok(digit)
+            }
+            None => Err(self.error("a digit")),
+        }
+    }
+
+    // A run of letters, digits and underscores like a monkey or a valve name
+    pub fn word(&mut self) -> ParseResult<&'a str> {
+        let len = self
+            .rest()
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(self.rest().len());
+        if len == 0 {
+            return Err(self.error("a word"));
+        }
+        Ok(self.take(len))
+    }
+
+    // Digits with an optional sign
+    pub fn number<T: FromStr>(&mut self) -> ParseResult<T> {
+        let rest = self.rest();
+        let sign = if rest.starts_with(['-', '+']) { 1 } else { 0 };
+        let len = rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+        match rest[..sign + len].parse::<T>() {
+            Ok(value) if len > 0 => {
+                self.take(sign + len);
+                Ok(value)
+            }
+            _ => Err(self.error("a number")),
+        }
+    }
+
+    // The rest of the line parsed with FromStr, like a whole line holding one number
+    pub fn value<T: FromStr>(&mut self, expected: &str) -> ParseResult<T> {
+        match self.rest().parse::<T>() {
+            Ok(value) => {
+                self.take(self.rest().len());
+                Ok(value)
+            }
+            Err(_) => Err(self.error(expected)),
+        }
+    }
+
+    // Picks the value of the first matching token
+    pub fn one_of<T: Copy>(&mut self, expected: &str, options: &[(&str, T)]) -> ParseResult<T> {
+        for (tag, value) in options {
+            if self.rest().starts_with(tag) {
+                self.take(tag.len());
+                return Ok(*value);
+            }
+        }
+        Err(self.error(expected))
+    }
+
+    pub fn end(&self) -> ParseResult<()> {
+        if self.is_end() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
+
+// Every line of the input with its number for a Cursor
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{numbered_lines, Cursor, ParseError};
+
+    #[test]
+    fn cursor_reads_tokens() {
+        let mut cursor = Cursor::new(1, "move 12 from -3 to b_c");
+        cursor.tag("move ").unwrap();
+        assert_eq!(cursor.number::<u32>(), Ok(12));
+        cursor.tag(" from ").unwrap();
+        assert_eq!(cursor.number::<i32>(), Ok(-3));
+        cursor.tag(" to ").unwrap();
+        assert_eq!(cursor.word(), Ok("b_c"));
+        assert!(cursor.end().is_ok());
+    }
+
+    #[test]
+    fn cursor_reports_column_and_found_token() {
+        let mut cursor = Cursor::new(4, "Monkey x:");
+        cursor.tag("Monkey ").unwrap();
+        assert_eq!(
+            cursor.number::<u32>(),
+            Err(ParseError::new(4, 8, "a number", "\"x:\""))
+        );
+        assert_eq!(
+            cursor.tag("Test"),
+            Err(ParseError::new(4, 8, "\"Test\"", "\"x:\""))
+        );
+
+        let cursor = Cursor::new(2, "");
+        assert_eq!(
+            cursor.error("a word").to_string(),
+            "line 2, column 1: expected a word, found end of line"
+        );
+    }
+
+    #[test]
+    fn cursor_one_of_and_value() {
+        let mut cursor = Cursor::new(1, "R 4");
+        assert_eq!(cursor.one_of("direction", &[("L", -1), ("R", 1)]), Ok(1));
+        cursor.char().unwrap();
+        assert_eq!(cursor.value::<u8>("steps"), Ok(4));
+        assert!(cursor.one_of("direction", &[("L", -1)]).is_err());
+
+        let mut cursor = Cursor::new(1, "7x");
+        assert_eq!(cursor.digit(), Ok(7));
+        assert!(cursor.digit().is_err());
+
+        let mut cursor = Cursor::new(1, "1x");
+        assert!(cursor.value::<u8>("steps").is_err());
+        assert_eq!(cursor.column(), 1);
+    }
+
+    #[test]
+    fn numbered_lines_start_at_one() {
+        let lines: Vec<_> = numbered_lines("a\nb\n").collect();
+        assert_eq!(lines, vec![(1, "a"), (2, "b")]);
+    }
+}
@@ -0,0 +1,286 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+// Order in which the states of the frontier are expanded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Bfs,
+    Dfs,
+    // the state with the highest upper bound first
+    BestFirst,
+    // depth first, the children with the highest upper bound first
+    BranchAndBound,
+}
+
+// A maximisation problem, every reached state is a candidate answer
+pub trait Problem {
+    type State: Clone;
+    type Key: Eq + Hash;
+    type Score: Copy + Ord;
+
+    fn successors(&self, state: &Self::State, next: &mut Vec<Self::State>);
+
+    // value of the state if the search stopped there
+    fn score(&self, state: &Self::State) -> Self::Score;
+
+    // no state reachable from this one scores more, states that can't beat
+    // the best score so far are pruned
+    fn upper_bound(&self, state: &Self::State) -> Self::Score;
+
+    // states with the same key lead to the same states and are expanded once
+    fn key(&self, state: &Self::State) -> Self::Key;
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub expanded: usize,
+    pub pruned: usize,
+    pub duplicates: usize,
+    pub max_frontier: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Found<S, V> {
+    pub state: S,
+    pub score: V,
+    pub stats: Stats,
+}
+
+// Frontier entry of the best-first heap, ordered by the bound only
+struct Entry<S, V> {
+    bound: V,
+    state: S,
+}
+
+impl<S, V: Ord> PartialEq for Entry<S, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bound == other.bound
+    }
+}
+
+impl<S, V: Ord> Eq for Entry<S, V> {}
+
+impl<S, V: Ord> PartialOrd for Entry<S, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, V: Ord> Ord for Entry<S, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bound.cmp(&other.bound)
+    }
+}
+
+enum Frontier<S, V> {
+    Queue(VecDeque<Entry<S, V>>),
+    Stack(Vec<Entry<S, V>>),
+    Heap(BinaryHeap<Entry<S, V>>),
+}
+
+impl<S, V: Ord> Frontier<S, V> {
+    fn new(mode: Mode) -> Frontier<S, V> {
+        match mode {
+            Mode::Bfs => Frontier::Queue(VecDeque::new()),
+            Mode::Dfs | Mode::BranchAndBound => Frontier::Stack(vec![]),
+            Mode::BestFirst => Frontier::Heap(BinaryHeap::new()),
+        }
+    }
+
+    fn push(&mut self, entry: Entry<S, V>) {
+        match self {
+            Frontier::Queue(queue) => queue.push_back(entry),
+            Frontier::Stack(stack) => stack.push(entry),
+            Frontier::Heap(heap) => heap.push(entry),
+        }
+    }
+
+    fn pop(&mut self) -> Option<Entry<S, V>> {
+        match self {
+            Frontier::Queue(queue) => queue.pop_front(),
+            Frontier::Stack(stack) => stack.pop(),
+            Frontier::Heap(heap) => heap.pop(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Frontier::Queue(queue) => queue.len(),
+            Frontier::Stack(stack) => stack.len(),
+            Frontier::Heap(heap) => heap.len(),
+        }
+    }
+}
+
+// Finds the best scoring state reachable from the start
+pub fn search<P: Problem>(problem: &P, start: P::State, mode: Mode) -> Found<P::State, P::Score> {
+    let mut stats = Stats::default();
+    let mut seen = HashSet::new();
+    let mut frontier = Frontier::new(mode);
+    let mut next = vec![];
+
+    let mut best = Found {
+        score: problem.score(&start),
+        state: start.clone(),
+        stats,
+    };
+    seen.insert(problem.key(&start));
+    frontier.push(Entry {
+        bound: problem.upper_bound(&start),
+        state: start,
+    });
+
+    while let Some(Entry { bound, state }) = frontier.pop() {
+        // the best score may have grown since the state was pushed
+        if bound <= best.score {
+            stats.pruned += 1;
+            // nothing left in the heap has a higher bound
+            if mode == Mode::BestFirst {
+                stats.pruned += frontier.len();
+                break;
+            }
+            continue;
+        }
+
+        stats.expanded += 1;
+        problem.successors(&state, &mut next);
+        let mut children = vec![];
+        for state in next.drain(..) {
+            if !seen.insert(problem.key(&state)) {
+                stats.duplicates += 1;
+                continue;
+            }
+            let score = problem.score(&state);
+            if score > best.score {
+                best.score = score;
+                best.state = state.clone();
+            }
+            let bound = problem.upper_bound(&state);
+            if bound <= best.score {
+                stats.pruned += 1;
+                continue;
+            }
+            children.push(Entry { bound, state });
+        }
+
+        match mode {
+            // the stack pops the highest bound first
+            Mode::BranchAndBound => children.sort_by_key(|x| x.bound),
+            // the stack pops the first successor first
+            Mode::Dfs => children.reverse(),
+            Mode::Bfs | Mode::BestFirst => {}
+        }
+        for child in children {
+            frontier.push(child);
+        }
+        stats.max_frontier = stats.max_frontier.max(frontier.len());
+    }
+
+    best.stats = stats;
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::{search, Mode, Problem};
+
+    // 0/1 knapsack, the state is the next item and the weight and value taken so far
+    struct Knapsack {
+        items: Vec<(u32, u32)>,
+        capacity: u32,
+    }
+
+    impl Problem for Knapsack {
+        type State = (usize, u32, u32);
+        type Key = (usize, u32, u32);
+        type Score = u32;
+
+        fn successors(&self, state: &Self::State, next: &mut Vec<Self::State>) {
+            let (index, weight, value) = *state;
+            if let Some((item_weight, item_value)) = self.items.get(index) {
+                if weight + item_weight <= self.capacity {
+                    next.push((index + 1, weight + item_weight, value + item_value));
+                }
+                next.push((index + 1, weight, value));
+            }
+        }
+
+        fn score(&self, state: &Self::State) -> u32 {
+            state.2
+        }
+
+        fn upper_bound(&self, state: &Self::State) -> u32 {
+            let rest: u32 = self.items[state.0..].iter().map(|x| x.1).sum();
+            state.2 + rest
+        }
+
+        fn key(&self, state: &Self::State) -> Self::Key {
+            *state
+        }
+    }
+
+    fn knapsack() -> Knapsack {
+        Knapsack {
+            items: vec![(5, 10), (4, 40), (6, 30), (3, 50), (2, 5), (7, 25)],
+            capacity: 10,
+        }
+    }
+
+    #[test]
+    fn every_mode_finds_the_best() {
+        let problem = knapsack();
+        for mode in [Mode::Bfs, Mode::Dfs, Mode::BestFirst, Mode::BranchAndBound] {
+            let found = search(&problem, (0, 0, 0), mode);
+            assert_eq!(found.score, 95, "{:?}", mode);
+            assert_eq!(found.state.1, 9, "{:?}", mode);
+        }
+    }
+
+    #[test]
+    fn bounds_prune_states() {
+        let problem = knapsack();
+        let bfs = search(&problem, (0, 0, 0), Mode::Bfs).stats;
+        let best_first = search(&problem, (0, 0, 0), Mode::BestFirst).stats;
+        let branch_and_bound = search(&problem, (0, 0, 0), Mode::BranchAndBound).stats;
+        assert!(bfs.pruned > 0 && best_first.pruned > 0 && branch_and_bound.pruned > 0);
+        assert!(best_first.expanded < bfs.expanded);
+        // depth first keeps a single branch and its siblings around
+        assert!(branch_and_bound.max_frontier < bfs.max_frontier);
+    }
+
+    // steps of one or two up to the target, most states are reached twice
+    struct Stairs;
+
+    impl Problem for Stairs {
+        type State = u32;
+        type Key = u32;
+        type Score = u32;
+
+        fn successors(&self, state: &u32, next: &mut Vec<u32>) {
+            next.extend([state + 1, state + 2].into_iter().filter(|x| *x <= 10));
+        }
+
+        fn score(&self, state: &u32) -> u32 {
+            *state
+        }
+
+        fn upper_bound(&self, _: &u32) -> u32 {
+            10
+        }
+
+        fn key(&self, state: &u32) -> u32 {
+            *state
+        }
+    }
+
+    #[test]
+    fn duplicates_are_expanded_once() {
+        let found = search(&Stairs, 0, Mode::Bfs);
+        assert_eq!(found.score, 10);
+        assert!(found.stats.duplicates > 0);
+        assert!(found.stats.expanded <= 10);
+    }
+}
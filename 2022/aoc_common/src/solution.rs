@@ -0,0 +1,142 @@
+use std::{
+    any::Any,
+    fmt::{self, Display},
+};
+
+use crate::parse::ParseResult;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    // only for numbers too large for an i64
+    Unsigned(u64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{}", value),
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value.into())
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(i32, u32, i64, u16);
+
+macro_rules! answer_from_wide_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    match i64::try_from(value) {
+                        Ok(value) => Answer::Number(value),
+                        Err(_) => Answer::Unsigned(value as u64),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+// i64 has no From for these, an isize always fits on 64-bit targets
+answer_from_wide_number!(u64, isize, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+// Every day exposes both parts of the puzzle through this trait
+// so the runner can pick them up from one registry
+// Malformed input is reported as a ParseError instead of a panic
+pub trait Solution {
+    fn part_one(&self, input: &str) -> ParseResult<Answer>;
+    fn part_two(&self, input: &str) -> ParseResult<Answer>;
+
+    // Parses the input without solving so benchmarks can time it on its own
+    // Days that read the input line by line inside the parts have nothing to return
+    fn parse(&self, _input: &str) -> Option<ParseResult<Box<dyn Any>>> {
+        None
+    }
+
+    fn solve(&self, part: u8, input: &str) -> Option<ParseResult<Answer>> {
+        match part {
+            1 => Some(self.part_one(input)),
+            2 => Some(self.part_two(input)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Answer, ParseError, ParseResult, Solution};
+
+    struct Echo;
+
+    impl Solution for Echo {
+        fn part_one(&self, input: &str) -> ParseResult<Answer> {
+            Ok(input.lines().count().into())
+        }
+
+        fn part_two(&self, input: &str) -> ParseResult<Answer> {
+            match input.trim() {
+                "" => Err(ParseError::end_of_input(1, "a word")),
+                value => Ok(value.into()),
+            }
+        }
+    }
+
+    #[test]
+    fn solution_solve_dispatches_by_part() {
+        let solution: &dyn Solution = &Echo;
+        assert_eq!(solution.solve(1, "a\nb\n"), Some(Ok(Answer::Number(2))));
+        assert_eq!(
+            solution.solve(2, " ab\n"),
+            Some(Ok(Answer::Text("ab".to_string())))
+        );
+        assert_eq!(solution.solve(3, ""), None);
+        assert!(solution.parse("a\nb\n").is_none());
+    }
+
+    #[test]
+    fn solution_reports_parse_errors() {
+        let solution: &dyn Solution = &Echo;
+        let error = solution.solve(2, "\n").unwrap().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected a word, found end of input"
+        );
+    }
+
+    #[test]
+    fn answer_display() {
+        assert_eq!(Answer::from(42u64).to_string(), "42");
+        assert_eq!(Answer::from(42u64), Answer::Number(42));
+        assert_eq!(Answer::from(-3isize), Answer::Number(-3));
+        assert_eq!(Answer::from(u64::MAX), Answer::Unsigned(u64::MAX));
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from("VQZNJMWTR").to_string(), "VQZNJMWTR");
+    }
+}
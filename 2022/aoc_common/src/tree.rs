@@ -0,0 +1,260 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeId {
+    pub index: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathError {
+    Empty,
+    // ".." at the root
+    NoParent,
+    NotFound(String),
+}
+
+impl Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::Empty => write!(f, "the tree is empty"),
+            PathError::NoParent => write!(f, "the root has no parent"),
+            PathError::NotFound(name) => write!(f, "no child named {}", name),
+        }
+    }
+}
+
+impl Error for PathError {}
+
+#[derive(Debug, Clone)]
+pub struct Node<T> {
+    pub id: NodeId,
+    pub name: String,
+    pub parent: Option<NodeId>,
+    pub children: Vec<NodeId>,
+    pub payload: T,
+}
+
+#[derive(Debug)]
+pub struct Tree<T> {
+    pub current: Option<NodeId>,
+    pub nodes: Vec<Node<T>>,
+}
+
+impl<T> Default for Tree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Tree<T> {
+    pub fn new() -> Tree<T> {
+        Tree {
+            current: None,
+            nodes: vec![],
+        }
+    }
+
+    pub fn set_current(&mut self, value: NodeId) {
+        self.current = Some(value);
+    }
+
+    pub fn get_current_mut(&mut self) -> Option<&mut Node<T>> {
+        match self.current {
+            None => None,
+            Some(id) => {
+                let node = &mut self.nodes[id.index];
+                Some(node)
+            }
+        }
+    }
+
+    pub fn add_node(&mut self, name: String, payload: T) -> NodeId {
+        let id = NodeId {
+            index: self.nodes.len(),
+        };
+        let node = Node {
+            id,
+            name,
+            payload,
+            children: vec![],
+            parent: self.current,
+        };
+        self.nodes.push(node);
+        if let Some(c) = self.current {
+            self.nodes[c.index].children.push(id);
+        }
+        id
+    }
+
+    pub fn root(&self) -> Option<NodeId> {
+        self.nodes.first().map(|node| node.id)
+    }
+
+    pub fn get(&self, id: NodeId) -> &Node<T> {
+        &self.nodes[id.index]
+    }
+
+    pub fn get_mut(&mut self, id: NodeId) -> &mut Node<T> {
+        &mut self.nodes[id.index]
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.get(id).parent
+    }
+
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        &self.get(id).children
+    }
+
+    pub fn child(&self, id: NodeId, name: &str) -> Option<NodeId> {
+        self.children(id)
+            .iter()
+            .copied()
+            .find(|child| self.get(*child).name == name)
+    }
+
+    // Follows a path like "/a/b", "../c" or "d" from a node
+    pub fn resolve(&self, from: NodeId, path: &str) -> Result<NodeId, PathError> {
+        let mut id = from;
+        if path.starts_with('/') {
+            id = self.root().ok_or(PathError::Empty)?;
+        }
+        for name in path.split('/').filter(|name| !name.is_empty()) {
+            id = match name {
+                "." => id,
+                ".." => self.parent(id).ok_or(PathError::NoParent)?,
+                _ => self
+                    .child(id, name)
+                    .ok_or_else(|| PathError::NotFound(name.to_string()))?,
+            };
+        }
+        Ok(id)
+    }
+
+    // Moves the current node along a path, a tree without a current node
+    // starts from the root
+    pub fn cd(&mut self, path: &str) -> Result<NodeId, PathError> {
+        let from = self.current.or(self.root()).ok_or(PathError::Empty)?;
+        let id = self.resolve(from, path)?;
+        self.current = Some(id);
+        Ok(id)
+    }
+
+    // Every node below and including the given one, parents before children
+    pub fn pre_order(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let mut stack = vec![id];
+        std::iter::from_fn(move || {
+            let id = stack.pop()?;
+            stack.extend(self.children(id).iter().rev());
+            Some(id)
+        })
+    }
+
+    // Every node below and including the given one, children before parents
+    pub fn post_order(&self, id: NodeId) -> impl Iterator<Item = NodeId> {
+        let mut order = vec![];
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            order.push(id);
+            stack.extend(self.children(id));
+        }
+        order.into_iter().rev()
+    }
+
+    // Aggregates every subtree once, like the total size of every folder.
+    // The value of a node is at its index
+    pub fn fold<A: Clone>(&self, mut f: impl FnMut(&Node<T>, &[A]) -> A) -> Vec<A> {
+        let mut values: Vec<Option<A>> = vec![None; self.nodes.len()];
+        let roots = self.nodes.iter().filter(|node| node.parent.is_none());
+        for root in roots {
+            for id in self.post_order(root.id) {
+                let children: Vec<A> = self
+                    .children(id)
+                    .iter()
+                    .map(|child| values[child.index].clone().unwrap())
+                    .collect();
+                values[id.index] = Some(f(self.get(id), &children));
+            }
+        }
+        values.into_iter().map(|value| value.unwrap()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tree::{NodeId, PathError, Tree};
+
+    #[test]
+    fn tree_add_node_to_current() {
+        let mut tree = Tree::<u32>::new();
+        let root = tree.add_node("/".to_string(), 0);
+        tree.set_current(root);
+        let a = tree.add_node("a".to_string(), 1);
+        let b = tree.add_node("b".to_string(), 2);
+
+        assert_eq!(tree.nodes[root.index].children, vec![a, b]);
+        assert_eq!(tree.nodes[b.index].parent, Some(root));
+        assert_eq!(tree.child(root, "b"), Some(b));
+        assert_eq!(tree.child(root, "c"), None);
+    }
+
+    fn folders() -> Tree<u32> {
+        // / -> a -> (c, d), b
+        let mut tree = Tree::<u32>::new();
+        let root = tree.add_node("/".to_string(), 1);
+        tree.set_current(root);
+        let a = tree.add_node("a".to_string(), 2);
+        tree.add_node("b".to_string(), 4);
+        tree.set_current(a);
+        tree.add_node("c".to_string(), 8);
+        tree.add_node("d".to_string(), 16);
+        tree
+    }
+
+    #[test]
+    fn tree_cd_paths() {
+        let mut tree = folders();
+        let names = |tree: &Tree<u32>, id| tree.get(id).name.clone();
+
+        let c = tree.cd("c").unwrap();
+        assert_eq!(names(&tree, c), "c");
+        assert_eq!(
+            tree.cd("../d").map(|id| names(&tree, id)),
+            Ok("d".to_string())
+        );
+        assert_eq!(
+            tree.cd("/b").map(|id| names(&tree, id)),
+            Ok("b".to_string())
+        );
+        assert_eq!(tree.cd("x"), Err(PathError::NotFound("x".to_string())));
+        assert_eq!(tree.cd("/.."), Err(PathError::NoParent));
+        // a failed cd keeps the current node
+        assert_eq!(
+            tree.current.map(|id| names(&tree, id)),
+            Some("b".to_string())
+        );
+        assert_eq!(Tree::<u32>::new().cd("/"), Err(PathError::Empty));
+    }
+
+    #[test]
+    fn tree_orders_and_fold() {
+        let tree = folders();
+        let root = tree.root().unwrap();
+        let names = |ids: Vec<NodeId>| -> String {
+            ids.iter().map(|id| tree.get(*id).name.as_str()).collect()
+        };
+        assert_eq!(names(tree.pre_order(root).collect()), "/acdb");
+        assert_eq!(names(tree.post_order(root).collect()), "cdab/");
+
+        let mut calls = 0;
+        let sums = tree.fold(|node, children: &[u32]| {
+            calls += 1;
+            node.payload + children.iter().sum::<u32>()
+        });
+        assert_eq!(sums, vec![31, 26, 4, 8, 16]);
+        assert_eq!(calls, 5);
+    }
+}
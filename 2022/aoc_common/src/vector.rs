@@ -0,0 +1,98 @@
+use std::ops::{Add, Sub};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vector {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Vector {
+    pub fn new(x: isize, y: isize) -> Vector {
+        Vector { x, y }
+    }
+
+    pub fn zero() -> Vector {
+        Vector { x: 0, y: 0 }
+    }
+
+    pub fn min() -> Vector {
+        Vector {
+            x: isize::MIN,
+            y: isize::MIN,
+        }
+    }
+
+    pub fn max() -> Vector {
+        Vector {
+            x: isize::MAX,
+            y: isize::MAX,
+        }
+    }
+
+    pub fn as_tuple(&self) -> (isize, isize) {
+        (self.x, self.y)
+    }
+
+    // Distance along the axis for vectors lying on the same horizontal or vertical line
+    pub fn dist_to(&self, other: &Self) -> u32 {
+        if self.x == other.x {
+            (self.y - other.y).unsigned_abs() as u32
+        } else {
+            (self.x - other.x).unsigned_abs() as u32
+        }
+    }
+
+    // |a-c| + |b-d|
+    pub fn manhattan(&self, other: &Self) -> isize {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn norm(&mut self) {
+        self.x = self.x.signum();
+        self.y = self.y.signum();
+    }
+}
+
+impl Add for Vector {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Vector::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Vector;
+
+    #[test]
+    fn vector_norm() {
+        let mut v = Vector::new(10, -10);
+        v.norm();
+        assert_eq!(v, Vector::new(1, -1));
+
+        let mut v = Vector::new(0, 7);
+        v.norm();
+        assert_eq!(v, Vector::new(0, 1));
+    }
+
+    #[test]
+    fn vector_manhattan() {
+        let dist = Vector::new(8, 7).manhattan(&Vector::new(2, 10));
+        assert_eq!(dist, 9);
+    }
+
+    #[test]
+    fn vector_dist_to() {
+        assert_eq!(Vector::new(2, 4).dist_to(&Vector::new(2, 9)), 5);
+        assert_eq!(Vector::new(7, 4).dist_to(&Vector::new(2, 4)), 5);
+    }
+}
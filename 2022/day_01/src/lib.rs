@@ -0,0 +1,32 @@
+use aoc_common::{Answer, ParseResult, Solution};
+
+mod one;
+mod two;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn part_one(&self, input: &str) -> ParseResult<Answer> {
+        Ok(one::part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> ParseResult<Answer> {
+        Ok(two::part_two(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day01;
+    use aoc_common::{ParseError, Solution};
+
+    #[test]
+    fn empty_input() {
+        let error = ParseError::end_of_input(1, "calories of an elf");
+        assert_eq!(Day01.part_one(""), Err(error));
+        let error = ParseError::end_of_input(1, "calories of three elves");
+        assert_eq!(Day01.part_two(""), Err(error));
+        let error = ParseError::end_of_input(4, "calories of three elves");
+        assert_eq!(Day01.part_two("1\n\n2"), Err(error));
+    }
+}
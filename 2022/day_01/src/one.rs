@@ -0,0 +1,29 @@
+use aoc_common::{numbered_lines, Cursor, ParseError, ParseResult};
+
+pub fn part_one(input: &str) -> ParseResult<i32> {
+    let mut max = 0;
+    let mut buffer = 0;
+    let mut last = 0;
+
+    for (number, line) in numbered_lines(input) {
+        last = number;
+        if !line.is_empty() {
+            // collect buffer
+            let x = Cursor::new(number, line).value::<i32>("a number")?;
+            buffer += x;
+        } else {
+            // we got new max value
+            if buffer > max {
+                max = buffer;
+            }
+
+            // reset buffer
+            buffer = 0;
+        }
+    }
+
+    if last == 0 {
+        return Err(ParseError::end_of_input(1, "calories of an elf"));
+    }
+    Ok(max)
+}
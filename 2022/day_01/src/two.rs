@@ -0,0 +1,36 @@
+use aoc_common::{numbered_lines, Cursor, ParseError, ParseResult};
+use std::vec::Vec;
+
+pub fn part_two(input: &str) -> ParseResult<i32> {
+    let mut elfes: Vec<i32> = Vec::new();
+    let mut buffer = 0;
+    let mut last = 0;
+
+    for (number, line) in numbered_lines(input) {
+        last = number;
+        if !line.is_empty() {
+            // collect buffer
+            let x = Cursor::new(number, line).value::<i32>("a number")?;
+            buffer += x;
+        } else {
+            elfes.push(buffer);
+
+            // reset buffer
+            buffer = 0;
+        }
+    }
+
+    if buffer > 0 {
+        elfes.push(buffer);
+    }
+
+    if elfes.len() < 3 {
+        return Err(ParseError::end_of_input(
+            last + 1,
+            "calories of three elves",
+        ));
+    }
+
+    elfes.sort_by(|a, b| b.cmp(a));
+    Ok(elfes[0] + elfes[1] + elfes[2])
+}
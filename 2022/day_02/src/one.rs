@@ -1,7 +1,6 @@
-use std::collections::HashMap;
-use std::io;
+use aoc_common::{numbered_lines, Cursor, ParseResult};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 enum Shape {
     Rock,
     Scissors,
@@ -47,34 +46,33 @@ fn get_shape_score(shape: &Shape) -> i32 {
     }
 }
 
-fn main() {
-    let mut elf = HashMap::<&str, Shape>::new();
-    elf.insert("A", Shape::Rock);
-    elf.insert("B", Shape::Papper);
-    elf.insert("C", Shape::Scissors);
-
-    let mut you = HashMap::<&str, Shape>::new();
-    you.insert("X", Shape::Rock);
-    you.insert("Y", Shape::Papper);
-    you.insert("Z", Shape::Scissors);
+pub fn part_one(input: &str) -> ParseResult<i32> {
+    let elf = [
+        ("A", Shape::Rock),
+        ("B", Shape::Papper),
+        ("C", Shape::Scissors),
+    ];
+    let you = [
+        ("X", Shape::Rock),
+        ("Y", Shape::Papper),
+        ("Z", Shape::Scissors),
+    ];
 
     let mut total_score = 0;
 
-    for line in io::stdin().lines() {
-        let l = line.unwrap();
-        let s: Vec<&str> = l.split(" ").collect();
+    for (number, line) in numbered_lines(input) {
+        let mut cursor = Cursor::new(number, line);
+        let elf_turn = cursor.one_of("\"A\", \"B\" or \"C\"", &elf)?;
+        cursor.tag(" ")?;
+        let you_turn = cursor.one_of("\"X\", \"Y\" or \"Z\"", &you)?;
+        cursor.end()?;
 
-        let elf_turn = elf.get(s[0]).unwrap();
-        let you_turn = you.get(s[1]).unwrap();
+        let result = get_result(&you_turn, &elf_turn);
 
-        let result = get_result(you_turn, elf_turn);
-
-        let shape_score = get_shape_score(you_turn);
+        let shape_score = get_shape_score(&you_turn);
         let game_score = get_game_score(&result);
         total_score += shape_score + game_score;
-
-        println!("{:?}/{:?}: game={} shape={} total={}", elf_turn, you_turn, game_score, shape_score, total_score);
     }
 
-    println!("Total score = {}", total_score);
+    Ok(total_score)
 }
@@ -1,4 +1,4 @@
-use std::io;
+use aoc_common::{numbered_lines, Cursor, ParseResult};
 
 #[derive(Debug)]
 enum Shape {
@@ -46,16 +46,23 @@ fn get_shape_score(shape: &Shape) -> i32 {
     }
 }
 
-fn main() {
+pub fn part_two(input: &str) -> ParseResult<i32> {
     let mut total_score = 0;
 
-    for line in io::stdin().lines() {
-        let l = line.unwrap();
-        let s: Vec<&str> = l.split(" ").collect();
-
-        // let elf_turn = elf.get(s[0]).unwrap();
-        // let you_turn = you.get(s[1]).unwrap();
-        let (elf_turn, you_turn) = match (s[0], s[1]) {
+    for (number, line) in numbered_lines(input) {
+        let mut cursor = Cursor::new(number, line);
+        let elf = cursor.one_of(
+            "\"A\", \"B\" or \"C\"",
+            &[("A", "A"), ("B", "B"), ("C", "C")],
+        )?;
+        cursor.tag(" ")?;
+        let you = cursor.one_of(
+            "\"X\", \"Y\" or \"Z\"",
+            &[("X", "X"), ("Y", "Y"), ("Z", "Z")],
+        )?;
+        cursor.end()?;
+
+        let (elf_turn, you_turn) = match (elf, you) {
             ("A", "X") => (&Shape::Rock, &Shape::Scissors),
             ("A", "Y") => (&Shape::Rock, &Shape::Rock),
             ("A", "Z") => (&Shape::Rock, &Shape::Papper),
@@ -76,9 +83,7 @@ fn main() {
         let shape_score = get_shape_score(you_turn);
         let game_score = get_game_score(&result);
         total_score += shape_score + game_score;
-
-        println!("{:?}/{:?}: game={} shape={} total={}", elf_turn, you_turn, game_score, shape_score, total_score);
     }
 
-    println!("Total score = {}", total_score);
+    Ok(total_score)
 }
@@ -1,6 +1,6 @@
-use std::io;
+use aoc_common::{numbered_lines, Cursor, ParseResult};
 
-fn split_at_center(s: &String) -> (String, String) {
+fn split_at_center(s: &str) -> (String, String) {
     let len = s.len();
     let mid = len / 2;
 
@@ -27,29 +27,28 @@ const UPPERCASE_A: i32 = 'A' as i32;
 const LOWERCASE_OFFSET: i32 = LOWERCASE_A - 1;
 const UPPERCASE_OFFSET: i32 = UPPERCASE_A - 1 - 26;
 
-fn sum_char_numbers(s: &String) -> i32 {
-    let mut sum = 0 as i32;
+fn sum_char_numbers(s: &str) -> i32 {
+    let mut sum = 0_i32;
     for c in s.chars() {
-        if c >= 'a' && c <= 'z' {
+        if c.is_ascii_lowercase() {
             sum += c as i32 - LOWERCASE_OFFSET;
-        } else if c >= 'A' && c <= 'Z' {
+        } else if c.is_ascii_uppercase() {
             sum += c as i32 - UPPERCASE_OFFSET;
         }
     }
     sum
 }
 
-fn main() {
+pub fn part_one(input: &str) -> ParseResult<i32> {
     let mut intersection = String::new();
-    for line in io::stdin().lines() {
-        let items = line.unwrap();
-        let (a, b) = split_at_center(&items);
+    for (number, line) in numbered_lines(input) {
+        let (a, b) = split_at_center(line);
         let common = common_chars(a, b);
-        let x = common.chars().next().unwrap();
-
-        println!("{} priority={}", x, sum_char_numbers(&String::from(x)));
+        let x = common
+            .chars()
+            .next()
+            .ok_or_else(|| Cursor::new(number, line).error("an item in both compartments"))?;
         intersection.push(x);
     }
-    let total = sum_char_numbers(&intersection);
-    println!("Total priority = {}", total);
+    Ok(sum_char_numbers(&intersection))
 }
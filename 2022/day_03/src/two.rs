@@ -0,0 +1,60 @@
+use aoc_common::{ParseError, ParseResult};
+
+fn common_chars(a: &str, b: &str, c: &str) -> String {
+    let mut common = String::new();
+
+    for x in a.chars() {
+        if b.contains(x) && c.contains(x) {
+            common.push(x);
+        }
+    }
+
+    common
+}
+
+const LOWERCASE_A: i32 = 'a' as i32;
+const UPPERCASE_A: i32 = 'A' as i32;
+const LOWERCASE_OFFSET: i32 = LOWERCASE_A - 1;
+const UPPERCASE_OFFSET: i32 = UPPERCASE_A - 1 - 26;
+
+fn sum_char_numbers(s: &str) -> i32 {
+    let mut sum = 0_i32;
+    for c in s.chars() {
+        if c.is_ascii_lowercase() {
+            sum += c as i32 - LOWERCASE_OFFSET;
+        } else if c.is_ascii_uppercase() {
+            sum += c as i32 - UPPERCASE_OFFSET;
+        }
+    }
+    sum
+}
+
+// The badge is the only item in all three rucksacks of a group
+fn badge(v: &[&str; 3], last_line: usize) -> ParseResult<char> {
+    let common = common_chars(v[0], v[1], v[2]);
+    common
+        .chars()
+        .next()
+        .ok_or_else(|| ParseError::new(last_line, 1, "an item shared by the group", "none"))
+}
+
+pub fn part_two(input: &str) -> ParseResult<i32> {
+    let mut intersection = String::new();
+    let mut v = [""; 3];
+    let mut lines = 0;
+
+    for (count, line) in input.lines().enumerate() {
+        if count > 0 && count % 3 == 0 {
+            intersection.push(badge(&v, count)?);
+        }
+
+        let i = count % 3;
+        v[i] = line;
+        lines = count + 1;
+    }
+
+    // yea I know this is looks ugly a bit (Im talking about duplication)
+    intersection.push(badge(&v, lines)?);
+
+    Ok(sum_char_numbers(&intersection))
+}
@@ -0,0 +1,16 @@
+use aoc_common::{Answer, ParseResult, Solution};
+
+mod one;
+mod two;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn part_one(&self, input: &str) -> ParseResult<Answer> {
+        Ok(one::part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> ParseResult<Answer> {
+        Ok(two::part_two(input)?.into())
+    }
+}
@@ -0,0 +1,38 @@
+use aoc_common::{numbered_lines, Cursor, ParseResult};
+
+struct Range {
+    min: i32,
+    max: i32,
+}
+
+fn parse_range(cursor: &mut Cursor) -> ParseResult<Range> {
+    let min = cursor.number::<i32>()?;
+    cursor.tag("-")?;
+    let max = cursor.number::<i32>()?;
+
+    Ok(Range { min, max })
+}
+
+fn is_overlap(one: &Range, other: &Range) -> bool {
+    one.max >= other.min && one.min <= other.max
+}
+
+pub fn part_two(input: &str) -> ParseResult<i32> {
+    let mut count = 0;
+
+    for (number, line) in numbered_lines(input) {
+        let mut cursor = Cursor::new(number, line);
+        let r1 = parse_range(&mut cursor)?;
+        cursor.tag(",")?;
+        let r2 = parse_range(&mut cursor)?;
+        cursor.end()?;
+
+        let overlap = is_overlap(&r1, &r2) || is_overlap(&r2, &r1);
+
+        if overlap {
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
@@ -0,0 +1,53 @@
+use aoc_common::{Answer, ParseResult, Solution};
+
+mod one;
+mod two;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn part_one(&self, input: &str) -> ParseResult<Answer> {
+        Ok(one::part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> ParseResult<Answer> {
+        Ok(two::part_two(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day05;
+    use aoc_common::{ParseError, Solution};
+
+    fn with_move(action: &str) -> String {
+        let head = include_str!("../test.txt").split("\n\n").next().unwrap();
+        format!("{}\n\n{}\n", head, action)
+    }
+
+    #[test]
+    fn moves_that_cant_be_done() {
+        let error = Day05
+            .part_one(&with_move("move 4 from 2 to 1"))
+            .unwrap_err();
+        assert_eq!(error, ParseError::new(6, 6, "at most 3 crates", "\"4\""));
+        let error = Day05
+            .part_two(&with_move("move 1 from 2 to 2"))
+            .unwrap_err();
+        let expected = "a stack other than the one moved from";
+        assert_eq!(error, ParseError::new(6, 18, expected, "\"2\""));
+
+        let input = with_move("move 1 from 3 to 1");
+        let expected = "a crate left on stack 3";
+        let error = ParseError::end_of_input(7, expected);
+        assert_eq!(Day05.part_one(&input), Err(error.clone()));
+        assert_eq!(Day05.part_two(&input), Err(error));
+    }
+
+    #[test]
+    fn empty_input() {
+        let error = ParseError::end_of_input(1, "ids of stacks");
+        assert_eq!(Day05.part_one(""), Err(error.clone()));
+        assert_eq!(Day05.part_two(""), Err(error));
+    }
+}
@@ -0,0 +1,144 @@
+use aoc_common::{numbered_lines, Cursor, ParseError, ParseResult};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    fn new() -> Stack<T> {
+        Stack { items: Vec::new() }
+    }
+
+    fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    fn push_many(&mut self, items: Vec<T>) {
+        self.items.extend(items);
+    }
+
+    // The top n items in the order they are stacked
+    fn pop_many(&mut self, n: u32) -> Vec<T> {
+        let n = (n as usize).min(self.items.len());
+        self.items.split_off(self.items.len() - n)
+    }
+
+    fn top(&self) -> Option<&T> {
+        self.items.last()
+    }
+}
+
+fn read_head<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+) -> ParseResult<HashMap<u32, Stack<String>>> {
+    let mut stacks = HashMap::<u32, Stack<String>>::new();
+    let mut head: Vec<&str> = Vec::new();
+    let mut last = 0;
+
+    // read strin until empty row appear
+    for (number, line) in lines.by_ref() {
+        last = number;
+        if line.is_empty() {
+            break;
+        }
+        head.push(line);
+    }
+
+    // take last row cause it has ids of stacks
+    let ns = head
+        .pop()
+        .ok_or_else(|| ParseError::end_of_input(last + 1, "ids of stacks"))?;
+
+    // reverse stack definition rows to fill stacks in right direction
+    head.reverse();
+
+    // fill stacks
+    for (i, c) in ns.chars().enumerate() {
+        if !c.is_numeric() {
+            continue;
+        }
+
+        let n = c.to_digit(10).unwrap();
+        let mut stack = Stack::new();
+        for line in &head {
+            // rows may be trimmed when the rightmost stacks are low
+            let x = line.chars().nth(i).unwrap_or(' ');
+            if x == ' ' {
+                continue;
+            }
+
+            let payload = String::from(x);
+            stack.push(payload);
+        }
+
+        stacks.insert(n, stack);
+    }
+    Ok(stacks)
+}
+
+fn read_stack_id(cursor: &mut Cursor, stacks: &HashMap<u32, Stack<String>>) -> ParseResult<u32> {
+    let start = cursor.clone();
+    let id = cursor.number::<u32>()?;
+    if stacks.contains_key(&id) {
+        Ok(id)
+    } else {
+        Err(start.error("id of a stack"))
+    }
+}
+
+fn read_action(
+    number: usize,
+    line: &str,
+    stacks: &HashMap<u32, Stack<String>>,
+) -> ParseResult<(u32, u32, u32)> {
+    let mut cursor = Cursor::new(number, line);
+    cursor.tag("move ")?;
+    let amount_at = cursor.clone();
+    let amount = cursor.number::<u32>()?;
+    cursor.tag(" from ")?;
+    let from = read_stack_id(&mut cursor, stacks)?;
+    cursor.tag(" to ")?;
+    let to_at = cursor.clone();
+    let to = read_stack_id(&mut cursor, stacks)?;
+    cursor.end()?;
+
+    // crates can only be moved from the stacks as they are at this line
+    let size = stacks.get(&from).map_or(0, |x| x.items.len());
+    if amount as usize > size {
+        return Err(amount_at.error(&format!("at most {} crates", size)));
+    }
+    if to == from {
+        return Err(to_at.error("a stack other than the one moved from"));
+    }
+
+    Ok((amount, from, to))
+}
+
+pub fn part_one(input: &str) -> ParseResult<String> {
+    let mut lines = numbered_lines(input);
+    let head = &mut read_head(&mut lines)?;
+
+    for (number, line) in lines {
+        let (amount, from, to) = read_action(number, line, head)?;
+
+        // the crates are moved one at a time, so they end up reversed
+        let mut items = head
+            .get_mut(&from)
+            .map(|x| x.pop_many(amount))
+            .unwrap_or_default();
+        items.reverse();
+        head.entry(to).or_insert_with(Stack::new).push_many(items);
+    }
+
+    let end = input.lines().count() + 1;
+    let mut tops: Vec<_> = head.iter().collect();
+    tops.sort_by_key(|(key, _)| **key);
+    let out = tops.into_iter().map(|(key, stack)| {
+        let expected = format!("a crate left on stack {}", key);
+        let top = stack.top().cloned();
+        top.ok_or_else(|| ParseError::end_of_input(end, &expected))
+    });
+    out.collect()
+}
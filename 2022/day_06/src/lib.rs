@@ -0,0 +1,29 @@
+use aoc_common::{Answer, ParseResult, Solution};
+
+mod one;
+mod two;
+
+pub struct Day06;
+
+// a single signal gives a plain number, several signals are listed comma separated
+fn scanned(values: Vec<u32>) -> Answer {
+    match values[..] {
+        [value] => value.into(),
+        _ => values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+            .into(),
+    }
+}
+
+impl Solution for Day06 {
+    fn part_one(&self, input: &str) -> ParseResult<Answer> {
+        Ok(scanned(one::part_one(input)))
+    }
+
+    fn part_two(&self, input: &str) -> ParseResult<Answer> {
+        Ok(scanned(two::part_two(input)))
+    }
+}
@@ -1,18 +1,18 @@
-use std::{collections::HashSet, io};
+use std::collections::HashSet;
 
 const SIZE_OF_SIGNAL_START: usize = 14;
 
-fn is_buffer_unique(items: &Vec<char>) -> bool {
+fn is_buffer_unique(items: &[char]) -> bool {
     let size = items.len();
     let mut s = HashSet::<&char>::new();
     for c in items {
-        s.insert(&c);
+        s.insert(c);
     }
 
     s.len() == size
 }
 
-fn read_signal(value: String) -> u32 {
+fn read_signal(value: &str) -> u32 {
     let mut s = Vec::<char>::new();
     for (i, c) in value.chars().enumerate() {
         s.push(c);
@@ -26,13 +26,10 @@ fn read_signal(value: String) -> u32 {
         }
     }
 
-    return 0;
+    0
 }
 
-fn main() {
-    for x in io::stdin().lines() {
-        let line = x.unwrap();
-        let scanned = read_signal(line);
-        println!("Scanned {} characters", scanned);
-    }
+// every line of input is a separate signal
+pub fn part_two(input: &str) -> Vec<u32> {
+    input.lines().map(read_signal).collect()
 }
@@ -0,0 +1,189 @@
+use aoc_common::{numbered_lines, Cursor, NodeId, ParseError, ParseResult, PathError, Tree};
+
+#[derive(Debug)]
+struct Command {
+    line: usize,
+    name: String,
+    args: String,
+    output: Vec<Entry>,
+}
+
+#[derive(Debug)]
+struct File {
+    name: String,
+    size: u32,
+}
+
+#[derive(Debug)]
+enum Entry {
+    Dir(String),
+    File(File),
+}
+
+fn is_command(value: &str) -> bool {
+    value.starts_with('$')
+}
+
+fn parse_command(number: usize, value: &str) -> ParseResult<(String, String)> {
+    let mut cursor = Cursor::new(number, value);
+    cursor.tag("$ ")?;
+    let command = cursor.one_of("\"cd\" or \"ls\"", &[("cd", "cd"), ("ls", "ls")])?;
+    if command == "ls" {
+        cursor.end()?;
+        return Ok((String::from("ls"), String::new()));
+    }
+
+    cursor.tag(" ")?;
+    if cursor.is_end() {
+        return Err(cursor.error("a path"));
+    }
+    Ok((String::from("cd"), String::from(cursor.rest())))
+}
+
+// ls prints "dir <name>" or "<size> <name>"
+fn parse_entry(number: usize, value: &str) -> ParseResult<Entry> {
+    let mut cursor = Cursor::new(number, value);
+    let size = if cursor.tag("dir ").is_ok() {
+        None
+    } else {
+        let size = cursor
+            .number::<u32>()
+            .map_err(|_| cursor.error("\"dir\" or a file size"))?;
+        cursor.tag(" ")?;
+        Some(size)
+    };
+    if cursor.is_end() {
+        return Err(cursor.error("a name"));
+    }
+
+    let name = String::from(cursor.rest());
+    Ok(match size {
+        None => Entry::Dir(name),
+        Some(size) => Entry::File(File { name, size }),
+    })
+}
+
+fn read_input(input: &str) -> ParseResult<Vec<Command>> {
+    let mut line = 0;
+    let mut command = String::new();
+    let mut args = String::new();
+    let mut output = Vec::<Entry>::new();
+    let mut items = Vec::<Command>::new();
+    for (number, value) in numbered_lines(input) {
+        // collecting output
+        if !is_command(value) {
+            output.push(parse_entry(number, value)?);
+            continue;
+        }
+
+        // run command with collected output
+        if !command.is_empty() {
+            items.push(Command {
+                line,
+                name: command,
+                args,
+                output,
+            });
+        }
+
+        (command, args) = parse_command(number, value)?;
+        line = number;
+        output = Vec::<Entry>::new();
+    }
+
+    // add last command at the end of the loop
+    items.push(Command {
+        line,
+        name: command,
+        args,
+        output,
+    });
+    Ok(items)
+}
+
+// Total size of the files in every folder and its subfolders
+fn folder_sizes(tree: &Tree<Vec<File>>) -> Vec<u32> {
+    tree.fold(|node, children: &[u32]| {
+        node.payload.iter().map(|f| f.size).sum::<u32>() + children.iter().sum::<u32>()
+    })
+}
+
+fn flat_fs(
+    root: &Tree<Vec<File>>,
+    sizes: &[u32],
+    id: NodeId,
+    padding: usize,
+) -> Vec<(String, u32, usize)> {
+    let mut nodes: Vec<(String, u32, usize)> = vec![];
+    let node = root.get(id);
+    nodes.push((node.name.clone(), sizes[id.index], padding));
+
+    for f in &node.children {
+        nodes.extend(flat_fs(root, sizes, *f, padding + 1));
+    }
+    for f in &node.payload {
+        nodes.push((f.name.clone(), f.size, padding + 1));
+    }
+    nodes
+}
+
+#[allow(dead_code)]
+fn print_fs(tree: &Tree<Vec<File>>) {
+    let items = flat_fs(tree, &folder_sizes(tree), NodeId { index: 0 }, 0);
+    for (name, size, padding) in items {
+        if size > 0 {
+            println!("{} - {} (size={})", " ".repeat(padding), name, size);
+        } else {
+            println!("{} - {}", " ".repeat(padding), name);
+        }
+    }
+}
+
+// Replays the commands, ls adds the entries to the current folder
+fn read_tree(input: &str) -> ParseResult<Tree<Vec<File>>> {
+    let mut tree = Tree::<Vec<File>>::new();
+    let root = tree.add_node("/".to_string(), vec![]);
+    tree.set_current(root);
+
+    for x in read_input(input)? {
+        if x.name == "cd" {
+            // "$ cd <path>" has the path at the sixth column
+            tree.cd(&x.args).map_err(|error| match error {
+                PathError::NoParent => {
+                    ParseError::new(x.line, 6, "a folder below the root", "\"..\"")
+                }
+                _ => ParseError::new(x.line, 6, "a known folder", &format!("\"{}\"", x.args)),
+            })?;
+        }
+        if x.name == "ls" {
+            for entry in x.output {
+                match entry {
+                    Entry::Dir(name) => {
+                        tree.add_node(name, vec![]);
+                    }
+                    Entry::File(file) => {
+                        if let Some(node) = tree.get_current_mut() {
+                            node.payload.push(file);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(tree)
+}
+
+const DISK: u32 = 70000000;
+const GOAL: u32 = 30000000;
+
+// The smallest folder to delete to free enough space, deleting the root always does
+pub fn part_two(input: &str) -> ParseResult<u32> {
+    let sizes = folder_sizes(&read_tree(input)?);
+    let free = DISK.saturating_sub(sizes[0]);
+    Ok(sizes
+        .iter()
+        .copied()
+        .filter(|size| free + size >= GOAL)
+        .min()
+        .unwrap_or(0))
+}
@@ -0,0 +1,29 @@
+use aoc_common::{grid::NEIGHBOURS_4, Cells, Grid, ParseResult, Vector};
+use std::any::Any;
+
+fn read_input(input: &str) -> ParseResult<Grid<u32>> {
+    Grid::parse(input, "a digit", |c| c.to_digit(10))
+}
+
+pub fn parse(input: &str) -> ParseResult<Box<dyn Any>> {
+    Ok(Box::new(read_input(input)?))
+}
+
+// every tree towards the edge is lower
+fn is_visible(grid: &Grid<u32>, position: Vector) -> bool {
+    let value = grid[position];
+    NEIGHBOURS_4.iter().any(|direction| {
+        grid.ray(position, *direction)
+            .all(|(_, cell)| *cell < value)
+    })
+}
+
+pub fn part_one(input: &str) -> ParseResult<u32> {
+    let grid = read_input(input)?;
+    let count = grid
+        .positions()
+        .filter(|position| is_visible(&grid, *position))
+        .count();
+
+    Ok(count as u32)
+}
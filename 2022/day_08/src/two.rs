@@ -0,0 +1,34 @@
+use aoc_common::{grid::NEIGHBOURS_4, Cells, Grid, ParseResult, Vector};
+
+fn read_input(input: &str) -> ParseResult<Grid<u32>> {
+    Grid::parse(input, "a digit", |c| c.to_digit(10))
+}
+
+// trees seen up to and including the first one that is not lower
+fn look(grid: &Grid<u32>, position: Vector, direction: Vector) -> u32 {
+    let value = grid[position];
+    let mut count = 0;
+    for (_, cell) in grid.ray(position, direction) {
+        count += 1;
+        if *cell >= value {
+            break;
+        }
+    }
+    count
+}
+
+pub fn part_two(input: &str) -> ParseResult<u32> {
+    let grid = read_input(input)?;
+    let max_value = grid
+        .positions()
+        .map(|position| {
+            NEIGHBOURS_4
+                .iter()
+                .map(|direction| look(&grid, position, *direction))
+                .product()
+        })
+        .max()
+        .unwrap_or(0);
+
+    Ok(max_value)
+}
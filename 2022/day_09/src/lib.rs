@@ -0,0 +1,21 @@
+use aoc_common::{Answer, ParseResult, Solution};
+use std::any::Any;
+
+mod one;
+mod two;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn parse(&self, input: &str) -> Option<ParseResult<Box<dyn Any>>> {
+        Some(one::parse(input))
+    }
+
+    fn part_one(&self, input: &str) -> ParseResult<Answer> {
+        Ok(one::part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> ParseResult<Answer> {
+        Ok(two::part_two(input)?.into())
+    }
+}
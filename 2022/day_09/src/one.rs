@@ -1,4 +1,5 @@
-use std::{collections::HashSet, io};
+use aoc_common::{numbered_lines, Cursor, ParseResult};
+use std::{any::Any, collections::HashSet};
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 struct Vector {
@@ -6,7 +7,7 @@ struct Vector {
     y: i32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 enum Direction {
     Left,
     Right,
@@ -20,34 +21,29 @@ struct Move {
     steps: u32,
 }
 
-fn read_input() -> Vec<Move> {
+fn read_input(input: &str) -> ParseResult<Vec<Move>> {
     let mut moves: Vec<Move> = vec![];
-    for x in io::stdin().lines() {
-        match x {
-            Err(error) => {
-                panic!("{}", error);
-            }
-            Ok(value) => {
-                let row: Vec<&str> = value.split(" ").collect();
-                if row.len() == 2 {
-                    let steps = row[1].parse::<u32>().unwrap();
-                    let direction = match row[0] {
-                        "L" => Direction::Left,
-                        "R" => Direction::Right,
-                        "U" => Direction::Up,
-                        "D" => Direction::Down,
-                        _ => {
-                            panic!("Input data is wrong");
-                        }
-                    };
-                    moves.push(Move { direction, steps });
-                } else {
-                    panic!("Input data is wrong");
-                }
-            }
-        }
+    for (number, value) in numbered_lines(input) {
+        let mut cursor = Cursor::new(number, value);
+        let direction = cursor.one_of(
+            "\"L\", \"R\", \"U\" or \"D\"",
+            &[
+                ("L", Direction::Left),
+                ("R", Direction::Right),
+                ("U", Direction::Up),
+                ("D", Direction::Down),
+            ],
+        )?;
+        cursor.tag(" ")?;
+        let steps = cursor.number::<u32>()?;
+        cursor.end()?;
+        moves.push(Move { direction, steps });
     }
-    moves
+    Ok(moves)
+}
+
+pub fn parse(input: &str) -> ParseResult<Box<dyn Any>> {
+    Ok(Box::new(read_input(input)?))
 }
 
 fn norm(n: i32) -> i32 {
@@ -79,14 +75,14 @@ fn move_tail(tail: &Vector, head: &Vector) -> Vector {
         return tail.clone();
     }
 
-    return Vector {
+    Vector {
         x: tail.x + norm(head.x - tail.x),
         y: tail.y + norm(head.y - tail.y),
-    };
+    }
 }
 
-fn main() {
-    let moves = read_input();
+pub fn part_one(input: &str) -> ParseResult<usize> {
+    let moves = read_input(input)?;
 
     let mut tail = Vector { x: 0, y: 0 };
     let mut head = Vector { x: 0, y: 0 };
@@ -117,9 +113,6 @@ fn main() {
         }
     }
 
-    println!("Head end: {:?}", head);
-    println!("Tail end: {:?}", tail);
-
     let unique_cells: HashSet<Vector> = HashSet::from_iter(log.iter().cloned());
-    println!("Unique tail positions: {:?}", unique_cells.len());
+    Ok(unique_cells.len())
 }
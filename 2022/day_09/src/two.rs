@@ -1,4 +1,5 @@
-use std::{collections::HashSet, io};
+use aoc_common::{numbered_lines, Cursor, ParseResult};
+use std::collections::HashSet;
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 struct Vector {
@@ -6,7 +7,7 @@ struct Vector {
     y: i32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 enum Direction {
     Left,
     Right,
@@ -20,34 +21,25 @@ struct Move {
     steps: u32,
 }
 
-fn read_input() -> Vec<Move> {
+fn read_input(input: &str) -> ParseResult<Vec<Move>> {
     let mut moves: Vec<Move> = vec![];
-    for x in io::stdin().lines() {
-        match x {
-            Err(error) => {
-                panic!("{}", error);
-            }
-            Ok(value) => {
-                let row: Vec<&str> = value.split(" ").collect();
-                if row.len() == 2 {
-                    let steps = row[1].parse::<u32>().unwrap();
-                    let direction = match row[0] {
-                        "L" => Direction::Left,
-                        "R" => Direction::Right,
-                        "U" => Direction::Up,
-                        "D" => Direction::Down,
-                        _ => {
-                            panic!("Input data is wrong");
-                        }
-                    };
-                    moves.push(Move { direction, steps });
-                } else {
-                    panic!("Input data is wrong");
-                }
-            }
-        }
+    for (number, value) in numbered_lines(input) {
+        let mut cursor = Cursor::new(number, value);
+        let direction = cursor.one_of(
+            "\"L\", \"R\", \"U\" or \"D\"",
+            &[
+                ("L", Direction::Left),
+                ("R", Direction::Right),
+                ("U", Direction::Up),
+                ("D", Direction::Down),
+            ],
+        )?;
+        cursor.tag(" ")?;
+        let steps = cursor.number::<u32>()?;
+        cursor.end()?;
+        moves.push(Move { direction, steps });
     }
-    moves
+    Ok(moves)
 }
 
 fn norm(n: i32) -> i32 {
@@ -79,14 +71,14 @@ fn move_tail(tail: &Vector, head: &Vector) -> Vector {
         return tail.clone();
     }
 
-    return Vector {
+    Vector {
         x: tail.x + norm(head.x - tail.x),
         y: tail.y + norm(head.y - tail.y),
-    };
+    }
 }
 
-fn main() {
-    let moves = read_input();
+pub fn part_two(input: &str) -> ParseResult<usize> {
+    let moves = read_input(input)?;
 
     const HEAD: usize = 0;
     const TAIL: usize = 9;
@@ -120,16 +112,13 @@ fn main() {
                 let head = &knots[i - 1];
                 let tail = &knots[i];
 
-                knots[i] = move_tail(&tail, &head);
+                knots[i] = move_tail(tail, head);
             }
 
             log.push(knots[TAIL].clone());
         }
     }
 
-    println!("Head end: {:?}", knots[HEAD]);
-    println!("Tail end: {:?}", knots[TAIL]);
-
     let unique_cells: HashSet<Vector> = HashSet::from_iter(log.iter().cloned());
-    println!("Unique tail positions: {:?}", unique_cells.len());
+    Ok(unique_cells.len())
 }
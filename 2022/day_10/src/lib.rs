@@ -0,0 +1,37 @@
+use aoc_common::{Answer, ParseResult, Solution};
+use std::any::Any;
+
+mod one;
+mod two;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn parse(&self, input: &str) -> Option<ParseResult<Box<dyn Any>>> {
+        Some(one::parse(input))
+    }
+
+    fn part_one(&self, input: &str) -> ParseResult<Answer> {
+        Ok(one::part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> ParseResult<Answer> {
+        Ok(two::part_two(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day10;
+    use aoc_common::{ParseError, Solution};
+
+    #[test]
+    fn empty_input() {
+        let error = ParseError::end_of_input(1, "instructions for 220 cycles");
+        assert_eq!(Day10.part_one(""), Err(error));
+        let error = ParseError::end_of_input(1, "instructions for 240 cycles");
+        assert_eq!(Day10.part_two(""), Err(error));
+        let error = ParseError::end_of_input(3, "instructions for 240 cycles");
+        assert_eq!(Day10.part_two("noop\naddx 3"), Err(error));
+    }
+}
@@ -0,0 +1,74 @@
+use aoc_common::{numbered_lines, Cursor, ParseError, ParseResult};
+use std::any::Any;
+
+#[derive(Debug, Clone, Copy)]
+enum Cmd {
+    Noop,
+    Addx,
+}
+
+#[derive(Debug)]
+struct Command {
+    cmd: Cmd,
+    value: Option<i32>,
+}
+
+fn read_input(input: &str) -> ParseResult<Vec<Command>> {
+    let mut commands: Vec<Command> = vec![];
+    for (number, line) in numbered_lines(input) {
+        let mut cursor = Cursor::new(number, line);
+        let cmd = cursor.one_of(
+            "\"noop\" or \"addx\"",
+            &[("noop", Cmd::Noop), ("addx", Cmd::Addx)],
+        )?;
+        let value = match cmd {
+            Cmd::Noop => None,
+            Cmd::Addx => {
+                cursor.tag(" ")?;
+                Some(cursor.number::<i32>()?)
+            }
+        };
+        cursor.end()?;
+        commands.push(Command { cmd, value });
+    }
+    Ok(commands)
+}
+
+pub fn parse(input: &str) -> ParseResult<Box<dyn Any>> {
+    Ok(Box::new(read_input(input)?))
+}
+
+pub fn part_one(input: &str) -> ParseResult<i32> {
+    let commands = read_input(input)?;
+    let mut register: i32 = 1;
+    let mut cycles: Vec<i32> = vec![];
+    cycles.push(register); // add 0 cycle value
+
+    for command in &commands {
+        match command.cmd {
+            Cmd::Noop => {
+                cycles.push(register);
+            }
+            Cmd::Addx => {
+                let value = command.value.unwrap();
+
+                cycles.push(register);
+                cycles.push(register);
+
+                register += value;
+            }
+        }
+    }
+
+    // every line is an instruction
+    if cycles.len() <= 220 {
+        let expected = "instructions for 220 cycles";
+        return Err(ParseError::end_of_input(commands.len() + 1, expected));
+    }
+
+    let interesting_signal_strengths: Vec<usize> = vec![20, 60, 100, 140, 180, 220];
+    Ok(interesting_signal_strengths
+        .iter()
+        .map(|cycle| *cycle as i32 * cycles[*cycle])
+        .sum())
+}
@@ -0,0 +1,86 @@
+use aoc_common::{numbered_lines, Cursor, ParseError, ParseResult};
+
+#[derive(Debug, Clone, Copy)]
+enum Cmd {
+    Noop,
+    Addx,
+}
+
+#[derive(Debug)]
+struct Command {
+    cmd: Cmd,
+    value: Option<i32>,
+}
+
+fn read_input(input: &str) -> ParseResult<Vec<Command>> {
+    let mut commands: Vec<Command> = vec![];
+    for (number, line) in numbered_lines(input) {
+        let mut cursor = Cursor::new(number, line);
+        let cmd = cursor.one_of(
+            "\"noop\" or \"addx\"",
+            &[("noop", Cmd::Noop), ("addx", Cmd::Addx)],
+        )?;
+        let value = match cmd {
+            Cmd::Noop => None,
+            Cmd::Addx => {
+                cursor.tag(" ")?;
+                Some(cursor.number::<i32>()?)
+            }
+        };
+        cursor.end()?;
+        commands.push(Command { cmd, value });
+    }
+    Ok(commands)
+}
+
+fn get_pixel(cursor: i32, sprite: i32) -> String {
+    if cursor >= sprite - 1 && cursor <= sprite + 1 {
+        return String::from("#");
+    }
+    String::from(".")
+}
+
+// renders the CRT screen, six rows of 40 pixels
+pub fn part_two(input: &str) -> ParseResult<String> {
+    let commands = read_input(input)?;
+    let mut register: i32 = 1;
+    let mut cycles: Vec<i32> = vec![];
+
+    for command in &commands {
+        match command.cmd {
+            Cmd::Noop => {
+                cycles.push(register);
+            }
+            Cmd::Addx => {
+                let value = command.value.unwrap();
+
+                cycles.push(register);
+                cycles.push(register);
+
+                register += value;
+            }
+        }
+    }
+
+    // every line is an instruction
+    if cycles.len() < 240 {
+        let expected = "instructions for 240 cycles";
+        return Err(ParseError::end_of_input(commands.len() + 1, expected));
+    }
+
+    let mut screen = String::new();
+    let mut i: usize = 0;
+    for row in 0..6 {
+        if row > 0 {
+            screen.push('\n');
+        }
+        for cursor in 0..40 {
+            let sprite = cycles[i];
+            let pixel = get_pixel(cursor, sprite);
+            screen.push_str(&pixel);
+
+            i += 1;
+        }
+    }
+    Ok(screen)
+}
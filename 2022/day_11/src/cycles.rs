@@ -0,0 +1,123 @@
+use crate::monkey::{self, Monkey};
+use aoc_common::{CycleFinder, ParseResult};
+
+// Moves an item through one round without relief, from the monkey that
+// holds it at the start to the one that holds it at the end. Every monkey
+// that inspects it on the way is counted
+fn item_round(
+    monkeys: &[Monkey],
+    modulus: i64,
+    (mut monkey, mut level): (usize, i64),
+    inspected: &mut [i64],
+) -> ParseResult<(usize, i64)> {
+    loop {
+        let current = &monkeys[monkey];
+        level = current.inspect(level)? % modulus;
+        inspected[monkey] += 1;
+        let target = if level % current.test_value == 0 {
+            current.throw_true
+        } else {
+            current.throw_false
+        };
+        // monkeys that already had their turn see the item in the next round
+        if target <= monkey {
+            return Ok((target, level));
+        }
+        monkey = target;
+    }
+}
+
+// Inspections of every monkey caused by one item, the rounds are simulated
+// until the item is back in a state it had at the start of an earlier round
+fn item_inspections(
+    monkeys: &[Monkey],
+    modulus: i64,
+    start: (usize, i64),
+    rounds: usize,
+) -> ParseResult<Vec<i64>> {
+    // inspections of every monkey after each round, starting with none
+    let mut totals = vec![vec![0; monkeys.len()]];
+    let mut finder = CycleFinder::new();
+    let mut state = start;
+    let cycle = loop {
+        if let Some(cycle) = finder.push(state) {
+            break cycle;
+        }
+        if totals.len() > rounds {
+            return Ok(totals.pop().unwrap());
+        }
+        let mut inspected = totals.last().unwrap().clone();
+        state = item_round(monkeys, modulus, state, &mut inspected)?;
+        totals.push(inspected);
+    };
+    let counts = (0..monkeys.len()).map(|i| {
+        let values: Vec<i64> = totals.iter().map(|x| x[i]).collect();
+        cycle.extrapolate(&values, rounds)
+    });
+    Ok(counts.collect())
+}
+
+// Inspections of every monkey after any number of rounds without relief,
+// the items don't affect each other so each of them is followed on its own
+pub fn inspections(monkeys: &[Monkey], rounds: usize) -> ParseResult<Vec<u64>> {
+    let modulus = monkey::modulus(monkeys)?;
+    let mut totals = vec![0; monkeys.len()];
+    for (id, monkey) in monkeys.iter().enumerate() {
+        for level in &monkey.items {
+            let counts = item_inspections(monkeys, modulus, (id, level % modulus), rounds)?;
+            for (total, count) in totals.iter_mut().zip(counts) {
+                *total += count as u64;
+            }
+        }
+    }
+    Ok(totals)
+}
+
+// The inspections of the two busiest monkeys multiplied, which can be more
+// than a u64 holds after enough rounds
+pub fn monkey_business(inspections: &[u64]) -> u128 {
+    let mut inspections = inspections.to_vec();
+    inspections.sort_unstable_by(|a, b| b.cmp(a));
+    inspections.iter().take(2).map(|x| *x as u128).product()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cycles::{inspections, monkey_business},
+        monkey::read_input,
+        simulator::Simulator,
+    };
+
+    #[test]
+    fn same_counts_as_the_simulator() {
+        let monkeys = read_input(include_str!("../test.txt")).unwrap();
+        let mut simulator = Simulator::new(monkeys.clone(), 1).unwrap();
+        for rounds in [0, 1, 20, 1000, 10000] {
+            simulator.run(rounds - simulator.rounds()).unwrap();
+            assert_eq!(
+                inspections(&monkeys, rounds).unwrap(),
+                simulator.inspections(),
+                "after {} rounds",
+                rounds
+            );
+        }
+        assert_eq!(
+            monkey_business(&inspections(&monkeys, 10000).unwrap()),
+            2713310158
+        );
+    }
+
+    #[test]
+    fn a_trillion_rounds() {
+        let monkeys = read_input(include_str!("../test.txt")).unwrap();
+        let small = inspections(&monkeys, 1_000_000).unwrap();
+        let large = inspections(&monkeys, 1_000_000_000_000).unwrap();
+        // every monkey inspects at about the same rate all the time
+        for (small, large) in small.iter().zip(&large) {
+            let rate = *large as f64 / *small as f64 / 1e6;
+            assert!((rate - 1.0).abs() < 1e-3, "{:?} {:?}", small, large);
+        }
+        assert!(monkey_business(&large) > u64::MAX as u128);
+    }
+}
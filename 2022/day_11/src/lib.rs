@@ -0,0 +1,45 @@
+use aoc_common::{Answer, ParseResult, Solution};
+use std::any::Any;
+
+mod cycles;
+mod monkey;
+mod one;
+mod operation;
+mod simulator;
+mod two;
+
+pub use simulator::{Simulator, Throw};
+
+// The monkeys after some rounds, with every throw traced when asked for
+// looking at how the items move around
+pub fn simulate(input: &str, rounds: usize, divisor: i64, traced: bool) -> ParseResult<Simulator> {
+    let mut simulator = Simulator::new(monkey::read_input(input)?, divisor)?;
+    if traced {
+        simulator = simulator.traced();
+    }
+    simulator.run(rounds)?;
+    Ok(simulator)
+}
+
+// Part two for any number of rounds, each item is followed until its way
+// through the monkeys repeats
+pub fn monkey_business_after(input: &str, rounds: usize) -> ParseResult<u128> {
+    let inspections = cycles::inspections(&monkey::read_input(input)?, rounds)?;
+    Ok(cycles::monkey_business(&inspections))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn parse(&self, input: &str) -> Option<ParseResult<Box<dyn Any>>> {
+        Some(monkey::parse(input))
+    }
+
+    fn part_one(&self, input: &str) -> ParseResult<Answer> {
+        Ok(one::part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> ParseResult<Answer> {
+        Ok(two::part_two(input)?.into())
+    }
+}
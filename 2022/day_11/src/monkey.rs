@@ -0,0 +1,213 @@
+use crate::operation::{parse_operation, Operation};
+use aoc_common::{numbered_lines, Cursor, ParseError, ParseResult};
+use std::any::Any;
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    pub id: usize,
+    // starting worry levels
+    pub items: Vec<i64>,
+    pub operation: Operation,
+    // line and column of the operation, overflows are reported there
+    pub operation_at: (usize, usize),
+    pub test_value: i64,
+    // line and column of the test value, for a product of them that overflows
+    pub test_at: (usize, usize),
+    pub throw_true: usize,
+    pub throw_false: usize,
+}
+
+// A monkey to throw to, one of the others
+fn parse_target(cursor: &mut Cursor, line: usize, id: usize, count: usize) -> ParseResult<usize> {
+    let column = cursor.column();
+    let target = cursor.number::<usize>()?;
+    let found = format!("\"{}\"", target);
+    if target >= count {
+        let expected = format!("a monkey below {}", count);
+        return Err(ParseError::new(line, column, &expected, &found));
+    }
+    if target == id {
+        let expected = "a monkey other than itself";
+        return Err(ParseError::new(line, column, expected, &found));
+    }
+    cursor.end()?;
+    Ok(target)
+}
+
+// The monkey at position id of count monkeys, they are numbered in order
+fn parse_monkey(def: &[(usize, &str)], id: usize, count: usize) -> ParseResult<Monkey> {
+    // Monkey 0:
+    //   Starting items: 71, 86
+    //   Operation: new = old * 13
+    //   Test: divisible by 19
+    //     If true: throw to monkey 6
+    //     If false: throw to monkey 7
+    let last = def.last().map(|(number, _)| *number).unwrap_or(0);
+    let mut lines = def.iter().map(|(number, line)| Cursor::new(*number, line));
+    let mut next = |expected: &str| {
+        lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(last + 1, expected))
+    };
+
+    let mut line_1 = next("\"Monkey\"")?;
+    line_1.tag("Monkey ")?;
+    let column = line_1.column();
+    let number = line_1.number::<usize>()?;
+    if number != id {
+        let (expected, found) = (format!("\"{}\"", id), format!("\"{}\"", number));
+        return Err(ParseError::new(def[0].0, column, &expected, &found));
+    }
+    line_1.tag(":")?;
+    line_1.end()?;
+
+    let mut line_2 = next("\"Starting items\"")?;
+    line_2.tag("  Starting items: ")?;
+    let mut items: Vec<i64> = vec![];
+    loop {
+        items.push(line_2.number::<i64>()?);
+        if line_2.is_end() {
+            break;
+        }
+        line_2.tag(", ")?;
+    }
+
+    let mut line_3 = next("\"Operation\"")?;
+    line_3.tag("  Operation: ")?;
+    line_3.tag("new = ")?;
+    let operation_at = (def[2].0, line_3.column());
+    let operation = parse_operation(&mut line_3)?;
+
+    let mut line_4 = next("\"Test\"")?;
+    line_4.tag("  Test: divisible by ")?;
+    let test_at = (def[3].0, line_4.column());
+    let test_value = line_4.number::<i64>()?;
+    if test_value == 0 {
+        let ((line, column), expected) = (test_at, "a test value that isn't zero");
+        return Err(ParseError::new(line, column, expected, "\"0\""));
+    }
+    line_4.end()?;
+
+    let mut line_5 = next("\"If true\"")?;
+    line_5.tag("    If true: throw to monkey ")?;
+    let throw_true = parse_target(&mut line_5, def[4].0, id, count)?;
+
+    let mut line_6 = next("\"If false\"")?;
+    line_6.tag("    If false: throw to monkey ")?;
+    let throw_false = parse_target(&mut line_6, def[5].0, id, count)?;
+
+    Ok(Monkey {
+        id,
+        items,
+        operation,
+        operation_at,
+        test_value,
+        test_at,
+        throw_true,
+        throw_false,
+    })
+}
+
+impl Monkey {
+    // The worry level once the monkey has looked at the item
+    pub fn inspect(&self, level: i64) -> ParseResult<i64> {
+        self.operation.apply(level).map_err(|error| {
+            let (line, column) = self.operation_at;
+            let expected = "an operation that stays within an i64";
+            ParseError::new(line, column, expected, &error.to_string())
+        })
+    }
+}
+
+// The product of the tests, worry levels only matter modulo it
+pub fn modulus(monkeys: &[Monkey]) -> ParseResult<i64> {
+    monkeys.iter().try_fold(1, |product: i64, monkey| {
+        product.checked_mul(monkey.test_value).ok_or_else(|| {
+            let (line, column) = monkey.test_at;
+            let expected = "tests whose product stays within an i64";
+            ParseError::new(
+                line,
+                column,
+                expected,
+                &format!("\"{}\"", monkey.test_value),
+            )
+        })
+    })
+}
+
+pub fn read_input(input: &str) -> ParseResult<Vec<Monkey>> {
+    // the monkeys are split up first, a monkey can only throw to one that exists
+    let mut buffer: Vec<(usize, &str)> = vec![];
+    let mut defs: Vec<Vec<(usize, &str)>> = vec![];
+    for (number, value) in numbered_lines(input) {
+        if value.is_empty() {
+            defs.push(buffer);
+            buffer = vec![];
+        } else {
+            buffer.push((number, value));
+        }
+    }
+    if !buffer.is_empty() {
+        defs.push(buffer);
+    }
+    defs.iter()
+        .enumerate()
+        .map(|(id, def)| parse_monkey(def, id, defs.len()))
+        .collect()
+}
+
+pub fn parse(input: &str) -> ParseResult<Box<dyn Any>> {
+    Ok(Box::new(read_input(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::monkey::{modulus, read_input};
+    use aoc_common::ParseError;
+
+    fn monkeys(changes: &[(usize, &str)]) -> Vec<String> {
+        let mut lines: Vec<String> = include_str!("../test.txt")
+            .lines()
+            .map(String::from)
+            .collect();
+        for (index, line) in changes {
+            lines[*index] = line.to_string();
+        }
+        lines
+    }
+
+    #[test]
+    fn throws_between_existing_monkeys() {
+        let error = |changes| read_input(&monkeys(changes).join("\n")).unwrap_err();
+        assert_eq!(
+            error(&[(7, "Monkey 2:")]),
+            ParseError::new(8, 8, "\"1\"", "\"2\"")
+        );
+        let expected = "a test value that isn't zero";
+        assert_eq!(
+            error(&[(10, "  Test: divisible by 0")]),
+            ParseError::new(11, 22, expected, "\"0\"")
+        );
+        assert_eq!(
+            error(&[(4, "    If true: throw to monkey 4")]),
+            ParseError::new(5, 30, "a monkey below 4", "\"4\"")
+        );
+        let expected = "a monkey other than itself";
+        assert_eq!(
+            error(&[(12, "    If false: throw to monkey 1")]),
+            ParseError::new(13, 31, expected, "\"1\"")
+        );
+    }
+
+    #[test]
+    fn product_of_the_tests() {
+        let input = monkeys(&[]).join("\n");
+        assert_eq!(modulus(&read_input(&input).unwrap()), Ok(23 * 19 * 13 * 17));
+
+        let input = monkeys(&[(17, "  Test: divisible by 10000000000000000")]).join("\n");
+        let error = modulus(&read_input(&input).unwrap()).unwrap_err();
+        // the product only gets too large with the last test
+        let expected = "tests whose product stays within an i64";
+        assert_eq!(error, ParseError::new(25, 22, expected, "\"17\""));
+    }
+}
@@ -0,0 +1,8 @@
+use crate::{monkey::read_input, simulator::Simulator};
+use aoc_common::ParseResult;
+
+pub fn part_one(input: &str) -> ParseResult<u64> {
+    let mut simulator = Simulator::new(read_input(input)?, 3)?;
+    simulator.run(20)?;
+    Ok(simulator.monkey_business())
+}
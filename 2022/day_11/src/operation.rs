@@ -0,0 +1,293 @@
+use aoc_common::{Cursor, ParseResult};
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OperationError {
+    // byte offset into the expression and what was there
+    Unsupported { offset: usize, found: String },
+    Overflow,
+}
+
+impl Display for OperationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OperationError::Unsupported { offset, found } => {
+                write!(f, "unsupported \"{}\" at {}", found, offset)
+            }
+            OperationError::Overflow => write!(f, "worry level overflow"),
+        }
+    }
+}
+
+impl Error for OperationError {}
+
+// The right side of "new = old * 13", parsed once and evaluated for every item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operation {
+    Old,
+    Const(i64),
+    Add(Box<Operation>, Box<Operation>),
+    Mul(Box<Operation>, Box<Operation>),
+    // old * old, so it only needs one evaluation of old
+    Square(Box<Operation>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Old,
+    Number(i64),
+    Plus,
+    Times,
+    Open,
+    Close,
+}
+
+fn tokenize(text: &str) -> Result<Vec<(usize, Token)>, OperationError> {
+    let mut tokens = vec![];
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let offset = text.len() - rest.len();
+        let length = match c {
+            ' ' => 1,
+            '+' | '*' | '(' | ')' => {
+                let token = match c {
+                    '+' => Token::Plus,
+                    '*' => Token::Times,
+                    '(' => Token::Open,
+                    _ => Token::Close,
+                };
+                tokens.push((offset, token));
+                1
+            }
+            _ if c.is_ascii_digit() => {
+                let digits = rest
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                let value = rest[..digits]
+                    .parse()
+                    .map_err(|_| OperationError::Unsupported {
+                        offset,
+                        found: rest[..digits].to_string(),
+                    })?;
+                tokens.push((offset, Token::Number(value)));
+                digits
+            }
+            _ => {
+                let word = rest
+                    .find(|c: char| !c.is_ascii_alphanumeric())
+                    .unwrap_or(rest.len())
+                    .max(c.len_utf8());
+                if &rest[..word] != "old" {
+                    return Err(OperationError::Unsupported {
+                        offset,
+                        found: rest[..word].to_string(),
+                    });
+                }
+                tokens.push((offset, Token::Old));
+                word
+            }
+        };
+        rest = &rest[length..];
+    }
+    Ok(tokens)
+}
+
+// sum = product ("+" product)*, product = atom ("*" atom)*, atom = old | number | "(" sum ")"
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    index: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.index).map(|(_, token)| *token)
+    }
+
+    fn unexpected(&self) -> OperationError {
+        match self.tokens.get(self.index) {
+            Some((offset, token)) => OperationError::Unsupported {
+                offset: *offset,
+                found: match token {
+                    Token::Old => "old".to_string(),
+                    Token::Number(n) => n.to_string(),
+                    Token::Plus => "+".to_string(),
+                    Token::Times => "*".to_string(),
+                    Token::Open => "(".to_string(),
+                    Token::Close => ")".to_string(),
+                },
+            },
+            None => OperationError::Unsupported {
+                offset: self.end,
+                found: String::new(),
+            },
+        }
+    }
+
+    fn sum(&mut self) -> Result<Operation, OperationError> {
+        let mut operation = self.product()?;
+        while self.peek() == Some(Token::Plus) {
+            self.index += 1;
+            operation = Operation::Add(Box::new(operation), Box::new(self.product()?));
+        }
+        Ok(operation)
+    }
+
+    fn product(&mut self) -> Result<Operation, OperationError> {
+        let mut operation = self.atom()?;
+        while self.peek() == Some(Token::Times) {
+            self.index += 1;
+            let factor = self.atom()?;
+            operation = if factor == operation {
+                Operation::Square(Box::new(factor))
+            } else {
+                Operation::Mul(Box::new(operation), Box::new(factor))
+            };
+        }
+        Ok(operation)
+    }
+
+    fn atom(&mut self) -> Result<Operation, OperationError> {
+        let operation = match self.peek() {
+            Some(Token::Old) => Operation::Old,
+            Some(Token::Number(n)) => Operation::Const(n),
+            Some(Token::Open) => {
+                self.index += 1;
+                let operation = self.sum()?;
+                if self.peek() != Some(Token::Close) {
+                    return Err(self.unexpected());
+                }
+                operation
+            }
+            _ => return Err(self.unexpected()),
+        };
+        self.index += 1;
+        Ok(operation)
+    }
+}
+
+impl Operation {
+    pub fn parse(text: &str) -> Result<Operation, OperationError> {
+        let mut parser = Parser {
+            tokens: tokenize(text)?,
+            index: 0,
+            end: text.len(),
+        };
+        let operation = parser.sum()?;
+        if parser.peek().is_some() {
+            return Err(parser.unexpected());
+        }
+        Ok(operation)
+    }
+
+    pub fn apply(&self, old: i64) -> Result<i64, OperationError> {
+        let value = match self {
+            Operation::Old => Some(old),
+            Operation::Const(n) => Some(*n),
+            Operation::Add(a, b) => a.apply(old)?.checked_add(b.apply(old)?),
+            Operation::Mul(a, b) => a.apply(old)?.checked_mul(b.apply(old)?),
+            Operation::Square(a) => {
+                let a = a.apply(old)?;
+                a.checked_mul(a)
+            }
+        };
+        value.ok_or(OperationError::Overflow)
+    }
+}
+
+// The operation at the cursor up to the end of the line
+pub fn parse_operation(cursor: &mut Cursor) -> ParseResult<Operation> {
+    let text = cursor.rest();
+    Operation::parse(text).map_err(|error| {
+        let offset = match error {
+            OperationError::Unsupported { offset, .. } => offset,
+            OperationError::Overflow => 0,
+        };
+        // the cursor reports whatever is at the offset
+        cursor.tag(&text[..offset]).unwrap();
+        cursor.error("an operation of old")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::operation::{parse_operation, Operation, OperationError};
+    use aoc_common::{Cursor, ParseError};
+
+    #[test]
+    fn parse_the_puzzle_operations() {
+        let old = || Box::new(Operation::Old);
+        assert_eq!(
+            Operation::parse("old * 19"),
+            Ok(Operation::Mul(old(), Box::new(Operation::Const(19))))
+        );
+        assert_eq!(
+            Operation::parse("old + 6"),
+            Ok(Operation::Add(old(), Box::new(Operation::Const(6))))
+        );
+        assert_eq!(Operation::parse("old * old"), Ok(Operation::Square(old())));
+        assert_eq!(Operation::parse("old"), Ok(Operation::Old));
+    }
+
+    #[test]
+    fn precedence_and_parentheses() {
+        let apply = |text: &str, old: i64| Operation::parse(text).unwrap().apply(old).unwrap();
+        assert_eq!(apply("2 + old * 3", 5), 17);
+        assert_eq!(apply("(2 + old) * 3", 5), 21);
+        assert_eq!(apply("(old + 1) * (old + 1)", 5), 36);
+        assert_eq!(apply("old*old+old", 5), 30);
+        assert!(matches!(
+            Operation::parse("(old + 1) * (old + 1)"),
+            Ok(Operation::Square(_))
+        ));
+    }
+
+    #[test]
+    fn errors() {
+        let unsupported = |offset: usize, found: &str| {
+            Err(OperationError::Unsupported {
+                offset,
+                found: found.to_string(),
+            })
+        };
+        assert_eq!(Operation::parse("old - 3"), unsupported(4, "-"));
+        assert_eq!(Operation::parse("old / 3"), unsupported(4, "/"));
+        assert_eq!(Operation::parse("new * 3"), unsupported(0, "new"));
+        assert_eq!(Operation::parse("old *"), unsupported(5, ""));
+        assert_eq!(Operation::parse("old 3"), unsupported(4, "3"));
+        assert_eq!(Operation::parse("(old + 3"), unsupported(8, ""));
+        assert_eq!(Operation::parse("old + )"), unsupported(6, ")"));
+        assert_eq!(
+            Operation::parse("old + 99999999999999999999"),
+            unsupported(6, "99999999999999999999")
+        );
+
+        let square = Operation::parse("old * old").unwrap();
+        assert_eq!(square.apply(3_037_000_499), Ok(9_223_372_030_926_249_001));
+        assert_eq!(square.apply(3_037_000_500), Err(OperationError::Overflow));
+        let sum = Operation::parse("old + 1").unwrap();
+        assert_eq!(sum.apply(i64::MAX), Err(OperationError::Overflow));
+    }
+
+    #[test]
+    fn errors_point_into_the_line() {
+        let mut cursor = Cursor::new(3, "  Operation: new = old - 3");
+        cursor.tag("  Operation: new = ").unwrap();
+        let error = parse_operation(&mut cursor).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(3, 24, "an operation of old", "\"-\"")
+        );
+
+        let mut cursor = Cursor::new(3, "new = old *");
+        cursor.tag("new = ").unwrap();
+        let error = parse_operation(&mut cursor).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(3, 12, "an operation of old", "end of line")
+        );
+    }
+}
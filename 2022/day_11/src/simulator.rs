@@ -0,0 +1,296 @@
+use crate::monkey::{self, Monkey};
+use aoc_common::ParseResult;
+use serde::Serialize;
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Item {
+    // the position in the starting items of all monkeys
+    id: usize,
+    level: i64,
+}
+
+// One inspection: the item, the monkey that held it and where it went
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Throw {
+    pub round: usize,
+    pub monkey: usize,
+    pub item: usize,
+    // the worry level before the inspection and after the relief
+    pub old: i64,
+    pub new: i64,
+    pub target: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Simulator {
+    monkeys: Vec<Monkey>,
+    queues: Vec<VecDeque<Item>>,
+    // worry levels are divided by this after every inspection
+    divisor: i64,
+    // levels only matter modulo the product of the tests when nothing is divided
+    modulus: Option<i64>,
+    round: usize,
+    // inspections of every monkey in every round so far
+    inspections: Vec<Vec<u64>>,
+    trace: Option<Vec<Throw>>,
+}
+
+impl Simulator {
+    pub fn new(monkeys: Vec<Monkey>, divisor: i64) -> ParseResult<Simulator> {
+        let mut next_id = 0;
+        let queues = monkeys
+            .iter()
+            .map(|monkey| {
+                let items = monkey.items.iter().map(|level| Item {
+                    id: next_id,
+                    level: *level,
+                });
+                let queue: VecDeque<Item> = items.collect();
+                next_id += queue.len();
+                queue
+            })
+            .collect();
+        let modulus = match divisor {
+            1 => Some(monkey::modulus(&monkeys)?),
+            _ => None,
+        };
+        Ok(Simulator {
+            monkeys,
+            queues,
+            divisor,
+            modulus,
+            round: 0,
+            inspections: vec![],
+            trace: None,
+        })
+    }
+
+    // Records every throw from now on
+    pub fn traced(mut self) -> Simulator {
+        self.trace.get_or_insert_with(Vec::new);
+        self
+    }
+
+    pub fn rounds(&self) -> usize {
+        self.round
+    }
+
+    pub fn run_round(&mut self) -> ParseResult<()> {
+        self.round += 1;
+        let mut inspected = vec![0; self.monkeys.len()];
+        for (i, count) in inspected.iter_mut().enumerate() {
+            // items thrown back to the same monkey wait for its next turn
+            for _ in 0..self.queues[i].len() {
+                let item = self.queues[i].pop_front().unwrap();
+                let monkey = &self.monkeys[i];
+                let mut level = monkey.inspect(item.level)? / self.divisor;
+                if let Some(modulus) = self.modulus {
+                    level %= modulus;
+                }
+                let target = if level % monkey.test_value == 0 {
+                    monkey.throw_true
+                } else {
+                    monkey.throw_false
+                };
+                *count += 1;
+                if let Some(trace) = &mut self.trace {
+                    trace.push(Throw {
+                        round: self.round,
+                        monkey: i,
+                        item: item.id,
+                        old: item.level,
+                        new: level,
+                        target,
+                    });
+                }
+                self.queues[target].push_back(Item { id: item.id, level });
+            }
+        }
+        self.inspections.push(inspected);
+        Ok(())
+    }
+
+    pub fn run(&mut self, rounds: usize) -> ParseResult<()> {
+        for _ in 0..rounds {
+            self.run_round()?;
+        }
+        Ok(())
+    }
+
+    // Inspections of every monkey over all rounds
+    pub fn inspections(&self) -> Vec<u64> {
+        let mut total = vec![0; self.monkeys.len()];
+        for round in &self.inspections {
+            for (sum, count) in total.iter_mut().zip(round) {
+                *sum += count;
+            }
+        }
+        total
+    }
+
+    // The inspections of the two busiest monkeys multiplied
+    pub fn monkey_business(&self) -> u64 {
+        let mut inspections = self.inspections();
+        inspections.sort_unstable_by(|a, b| b.cmp(a));
+        inspections.iter().take(2).product()
+    }
+
+    // The worry levels each monkey holds, in the order they'll be inspected
+    pub fn levels(&self) -> Vec<Vec<i64>> {
+        let levels = |queue: &VecDeque<Item>| queue.iter().map(|x| x.level).collect();
+        self.queues.iter().map(levels).collect()
+    }
+
+    pub fn trace(&self) -> &[Throw] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    pub fn trace_csv(&self) -> String {
+        let mut lines = vec!["round,monkey,item,old,new,target".to_string()];
+        for x in self.trace() {
+            lines.push(format!(
+                "{},{},{},{},{},{}",
+                x.round, x.monkey, x.item, x.old, x.new, x.target
+            ));
+        }
+        lines.join("\n")
+    }
+
+    pub fn trace_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self.trace())
+    }
+
+    // A row of inspections per round and a row with the totals
+    pub fn summary(&self) -> String {
+        let mut lines = vec![];
+        let mut header = format!("{:>7}", "round");
+        for monkey in &self.monkeys {
+            header += &format!("  {:>9}", format!("monkey {}", monkey.id));
+        }
+        lines.push(header);
+        let row = |label: String, counts: &[u64]| {
+            let counts = counts.iter().map(|x| format!("  {:>9}", x));
+            format!("{:>7}", label) + &counts.collect::<String>()
+        };
+        for (round, counts) in self.inspections.iter().enumerate() {
+            lines.push(row((round + 1).to_string(), counts));
+        }
+        lines.push(row("total".to_string(), &self.inspections()));
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{monkey::read_input, simulate, simulator::Simulator};
+    use aoc_common::ParseError;
+
+    fn example() -> Simulator {
+        Simulator::new(read_input(include_str!("../test.txt")).unwrap(), 3).unwrap()
+    }
+
+    #[test]
+    fn rounds_of_the_example() {
+        let mut simulator = example();
+        simulator.run_round().unwrap();
+        assert_eq!(
+            simulator.levels(),
+            [
+                vec![20, 23, 27, 26],
+                vec![2080, 25, 167, 207, 401, 1046],
+                vec![],
+                vec![],
+            ]
+        );
+        simulator.run(19).unwrap();
+        assert_eq!(simulator.rounds(), 20);
+        assert_eq!(simulator.inspections(), [101, 95, 7, 105]);
+        assert_eq!(simulator.monkey_business(), 10605);
+
+        let monkeys = read_input(include_str!("../test.txt")).unwrap();
+        let mut simulator = Simulator::new(monkeys, 1).unwrap();
+        simulator.run(20).unwrap();
+        assert_eq!(simulator.inspections(), [99, 97, 8, 103]);
+    }
+
+    #[test]
+    fn trace_of_the_first_round() {
+        let mut simulator = example().traced();
+        simulator.run_round().unwrap();
+        let trace = simulator.trace();
+        assert_eq!(trace.len(), 14);
+        // monkey 0 inspects 79: 79 * 19 / 3 = 500 and throws it to monkey 3
+        assert_eq!(
+            (
+                trace[0].monkey,
+                trace[0].item,
+                trace[0].old,
+                trace[0].new,
+                trace[0].target
+            ),
+            (0, 0, 79, 500, 3)
+        );
+        let csv = simulator.trace_csv();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("round,monkey,item,old,new,target"));
+        assert_eq!(lines.next(), Some("1,0,0,79,500,3"));
+        assert_eq!(csv.lines().count(), 15);
+
+        let json: serde_json::Value =
+            serde_json::from_str(&simulator.trace_json().unwrap()).unwrap();
+        assert_eq!(json[1]["new"], 620);
+        assert_eq!(json[1]["target"], 3);
+
+        let summary = simulator.summary();
+        let lines: Vec<&str> = summary.lines().collect();
+        assert_eq!(
+            lines[0],
+            "  round   monkey 0   monkey 1   monkey 2   monkey 3"
+        );
+        assert_eq!(
+            lines[1],
+            "      1          2          4          3          5"
+        );
+        assert_eq!(
+            lines[2],
+            "  total          2          4          3          5"
+        );
+    }
+
+    #[test]
+    fn only_traced_when_asked() {
+        let input = include_str!("../test.txt");
+        let simulator = simulate(input, 1, 3, false).unwrap();
+        assert!(simulator.trace().is_empty());
+        assert_eq!(simulator.inspections(), [2, 4, 3, 5]);
+        assert_eq!(simulate(input, 1, 3, true).unwrap().trace().len(), 14);
+    }
+
+    #[test]
+    fn overflow_points_at_the_operation() {
+        let input = [
+            "Monkey 0:",
+            "  Starting items: 4000000000",
+            "  Operation: new = old * old",
+            "  Test: divisible by 2",
+            "    If true: throw to monkey 1",
+            "    If false: throw to monkey 1",
+            "",
+            "Monkey 1:",
+            "  Starting items: 1",
+            "  Operation: new = old + 1",
+            "  Test: divisible by 3",
+            "    If true: throw to monkey 0",
+            "    If false: throw to monkey 0",
+        ];
+        let monkeys = read_input(&input.join("\n")).unwrap();
+        let mut simulator = Simulator::new(monkeys, 1).unwrap();
+        let error = simulator.run(1).unwrap_err();
+        let expected = "an operation that stays within an i64";
+        assert_eq!(
+            error,
+            ParseError::new(3, 20, expected, "worry level overflow")
+        );
+    }
+}
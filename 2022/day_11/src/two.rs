@@ -0,0 +1,9 @@
+use crate::{monkey::read_input, simulator::Simulator};
+use aoc_common::ParseResult;
+
+pub fn part_two(input: &str) -> ParseResult<u64> {
+    // without relief the levels are kept modulo the product of the tests
+    let mut simulator = Simulator::new(read_input(input)?, 1)?;
+    simulator.run(10000)?;
+    Ok(simulator.monkey_business())
+}
@@ -0,0 +1,145 @@
+use aoc_common::{graph::bfs, Cells, Grid, ParseError, ParseResult, Vector};
+use std::any::Any;
+
+const A: i32 = 'a' as i32;
+const Z: i32 = 'z' as i32;
+
+#[derive(Debug, Clone)]
+struct Landscape {
+    grid: Grid<i32>,
+}
+
+impl Landscape {
+    // end is at the same elevation or +1 step or lower
+    fn is_reachable(&self, start: &Location, end: &Location) -> bool {
+        let slope = self.elevation_at_step(start, end);
+        // elevation of destination cell can be much lower than current cell
+        if slope < 0 {
+            return true;
+        }
+        // or at most one higher than current
+        slope == 0 || slope == 1
+    }
+
+    fn elevation_at(&self, loc: &Location) -> i32 {
+        self.grid[*loc]
+    }
+
+    fn elevation_at_step(&self, start: &Location, end: &Location) -> i32 {
+        let a = self.elevation_at(start);
+        let b = self.elevation_at(end);
+        b - a
+    }
+
+    fn get_adjacent(&self, loc: &Location) -> Vec<Location> {
+        self.grid
+            .neighbours4(*loc)
+            .map(|(next, _)| next)
+            .filter(|next| self.is_reachable(loc, next))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+    Cover,
+    Far,
+}
+
+type Location = Vector;
+
+fn get_direction(start: &Location, end: &Location) -> Direction {
+    if start == end {
+        return Direction::Cover;
+    }
+
+    if start.x == end.x {
+        return if start.y > end.y {
+            Direction::Up
+        } else {
+            Direction::Down
+        };
+    }
+
+    if start.y == end.y {
+        return if start.x > end.x {
+            Direction::Left
+        } else {
+            Direction::Right
+        };
+    }
+
+    Direction::Far
+}
+
+fn route_to_directions(route: &[Location]) -> Vec<Direction> {
+    let init = route.len() - 1;
+    let mut result = vec![];
+    for s in 0..init {
+        let e = s + 1;
+        let start = route[s];
+        let end = route[e];
+        let d = get_direction(&start, &end);
+        result.push(d);
+    }
+    result
+}
+
+fn read_input(input: &str) -> ParseResult<(Location, Location, Landscape)> {
+    let map = Grid::parse(input, "an elevation", |c| {
+        (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c)
+    })?;
+
+    let lines = map.height();
+    let start = map
+        .find(|c| *c == 'S')
+        .ok_or_else(|| ParseError::end_of_input(lines + 1, "start \"S\""))?;
+    let end = map
+        .find(|c| *c == 'E')
+        .ok_or_else(|| ParseError::end_of_input(lines + 1, "end \"E\""))?;
+    let grid = map.map(|c| match c {
+        'S' => 0,
+        'E' => Z - A,
+        c => *c as i32 - A,
+    });
+    Ok((start, end, Landscape { grid }))
+}
+
+pub fn parse(input: &str) -> ParseResult<Box<dyn Any>> {
+    Ok(Box::new(read_input(input)?))
+}
+
+#[allow(dead_code)]
+fn print_route(end: &Location, land: &Landscape, route: &[Location]) {
+    let mut markers = land.grid.map(|_| '.');
+    let directions = route_to_directions(route);
+    for (loc, dir) in route.iter().zip(directions) {
+        markers[*loc] = match dir {
+            Direction::Cover => '*',
+            Direction::Left => '<',
+            Direction::Right => '>',
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Far => '@',
+        };
+    }
+    markers[*end] = 'E';
+    println!("{}", markers);
+}
+
+// A map whose climb can't be made points at the end
+fn unreachable_end(end: &Location, expected: &str) -> ParseError {
+    let (line, column) = (end.y as usize + 1, end.x as usize + 1);
+    ParseError::new(line, column, expected, "\"E\"")
+}
+
+pub fn part_one(input: &str) -> ParseResult<usize> {
+    let (start, end, landscape) = read_input(input)?;
+    let route = bfs(start, |loc| landscape.get_adjacent(loc), |loc| *loc == end)
+        .ok_or_else(|| unreachable_end(&end, "an \"E\" that \"S\" can reach"))?;
+    Ok(route.len() - 1)
+}
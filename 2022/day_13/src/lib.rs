@@ -0,0 +1,37 @@
+use aoc_common::{Answer, Packet, ParseResult, Solution};
+use std::any::Any;
+
+mod one;
+mod store;
+mod two;
+
+pub use store::PacketStore;
+
+// Every packet of the input in order
+pub fn read_packets(input: &str) -> ParseResult<PacketStore> {
+    let pairs = one::read_input(input)?;
+    Ok(pairs.into_iter().flat_map(|x| [x.left, x.right]).collect())
+}
+
+pub fn default_dividers() -> Vec<Packet> {
+    ["[[2]]", "[[6]]"]
+        .iter()
+        .map(|x| x.parse().unwrap())
+        .collect()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn parse(&self, input: &str) -> Option<ParseResult<Box<dyn Any>>> {
+        Some(one::parse(input))
+    }
+
+    fn part_one(&self, input: &str) -> ParseResult<Answer> {
+        Ok(one::part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> ParseResult<Answer> {
+        Ok(two::part_two(input)?.into())
+    }
+}
@@ -0,0 +1,161 @@
+use aoc_common::{numbered_lines, Cursor, Order, Packet, ParseError, ParseResult};
+use std::{any::Any, fmt::Debug};
+
+// Every packet of the input is a list
+fn parse_row(number: usize, row: &str) -> ParseResult<Packet> {
+    if !row.starts_with('[') {
+        return Err(Cursor::new(number, row).error("\"[\""));
+    }
+    Packet::parse(number, row)
+}
+
+#[derive(Debug)]
+pub struct Pair {
+    pub left: Packet,
+    pub right: Packet,
+}
+
+impl Pair {
+    fn compare(&self) -> bool {
+        self.left.compare(&self.right, Order::Packet).is_le()
+    }
+}
+
+pub fn read_input(input: &str) -> ParseResult<Vec<Pair>> {
+    let mut result = vec![];
+    let mut lines = numbered_lines(input);
+    while let Some((number, value)) = lines.next() {
+        let left = parse_row(number, value)?;
+        let (number, value) = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(number + 1, "a packet"))?;
+        let right = parse_row(number, value)?;
+        result.push(Pair { left, right });
+
+        // pairs are separated by an empty line
+        if let Some((number, value)) = lines.next() {
+            Cursor::new(number, value).end()?;
+        }
+    }
+    Ok(result)
+}
+
+pub fn parse(input: &str) -> ParseResult<Box<dyn Any>> {
+    Ok(Box::new(read_input(input)?))
+}
+
+pub fn part_one(input: &str) -> ParseResult<usize> {
+    let pairs = read_input(input)?;
+    let mut count = 0;
+    for (i, pair) in pairs.iter().enumerate() {
+        if pair.compare() {
+            count += i + 1;
+        }
+    }
+
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::one::{parse_row, Pair};
+    use aoc_common::Packet;
+    use aoc_common::ParseError;
+
+    #[test]
+    fn parse_row_reports_column() {
+        assert_eq!(
+            parse_row(3, "[1,x]"),
+            Err(ParseError::new(3, 4, "a number or \"[\"", "\"x]\""))
+        );
+        assert_eq!(
+            parse_row(3, "[1,2"),
+            Err(ParseError::new(3, 5, "\",\" or \"]\"", "end of line"))
+        );
+        assert!(parse_row(3, "[1]]").is_err());
+        assert!(parse_row(3, "1").is_err());
+    }
+
+    #[test]
+    fn pair_compare_equal_integer_list_of_same_size() {
+        let pair = Pair {
+            left: Packet::List(vec![
+                Packet::Integer(1),
+                Packet::Integer(2),
+                Packet::Integer(3),
+            ]),
+            right: Packet::List(vec![
+                Packet::Integer(1),
+                Packet::Integer(2),
+                Packet::Integer(3),
+            ]),
+        };
+        let result = pair.compare();
+        assert!(result);
+    }
+
+    #[test]
+    fn pair_compare_right_ordered_integer_list_of_same_size() {
+        let pair = Pair {
+            left: Packet::List(vec![
+                Packet::Integer(1),
+                Packet::Integer(1),
+                Packet::Integer(3),
+                Packet::Integer(1),
+                Packet::Integer(1),
+            ]),
+            right: Packet::List(vec![
+                Packet::Integer(1),
+                Packet::Integer(1),
+                Packet::Integer(5),
+                Packet::Integer(1),
+                Packet::Integer(1),
+            ]),
+        };
+        let result = pair.compare();
+        assert!(result);
+    }
+
+    #[test]
+    fn pair_compare_wrong_ordered_integer_list_of_same_size() {
+        let pair = Pair {
+            left: Packet::List(vec![
+                Packet::Integer(1),
+                Packet::Integer(8),
+                Packet::Integer(3),
+                Packet::Integer(1),
+                Packet::Integer(1),
+            ]),
+            right: Packet::List(vec![
+                Packet::Integer(1),
+                Packet::Integer(1),
+                Packet::Integer(5),
+                Packet::Integer(1),
+                Packet::Integer(1),
+            ]),
+        };
+        let result = pair.compare();
+        assert!(!result);
+    }
+
+    #[test]
+    fn pair_compare_right_complex() {
+        // [[4,4],4,4]
+        // [[4,4],4,4,4]
+        let pair = Pair {
+            left: Packet::List(vec![
+                Packet::List(vec![Packet::Integer(4), Packet::Integer(4)]),
+                Packet::Integer(4),
+                Packet::Integer(4),
+            ]),
+            right: Packet::List(vec![
+                Packet::List(vec![Packet::Integer(4), Packet::Integer(4)]),
+                Packet::Integer(4),
+                Packet::Integer(4),
+                Packet::Integer(4),
+            ]),
+        };
+        let result = pair.compare();
+        assert!(result);
+    }
+}
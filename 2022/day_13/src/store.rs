@@ -0,0 +1,130 @@
+use aoc_common::{Order, Packet};
+
+// Packets in the order they came in. Ranks are counted over all of them, so
+// no query needs the packets sorted, only the listing sorts them. Packets that
+// are equal in the puzzle order stay in the order they came in
+#[derive(Debug, Clone, Default)]
+pub struct PacketStore {
+    packets: Vec<Packet>,
+}
+
+impl PacketStore {
+    pub fn new() -> PacketStore {
+        PacketStore::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.packets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packets.is_empty()
+    }
+
+    // How many packets come before this one if it was inserted now
+    pub fn rank(&self, packet: &Packet) -> usize {
+        self.packets
+            .iter()
+            .filter(|x| x.compare(packet, Order::Packet).is_le())
+            .count()
+    }
+
+    // Returns the index the packet got in the sorted order
+    pub fn insert(&mut self, packet: Packet) -> usize {
+        let index = self.rank(&packet);
+        self.packets.push(packet);
+        index
+    }
+
+    // Stable, so equal packets keep their order
+    pub fn sorted(&self) -> Vec<&Packet> {
+        let mut packets: Vec<&Packet> = self.packets.iter().collect();
+        packets.sort_by(|a, b| a.compare(b, Order::Packet));
+        packets
+    }
+
+    // Positions of the dividers counting from 1 as if they were inserted
+    // after the packets one by one, multiplied
+    pub fn decoder_key(&self, dividers: &[Packet]) -> usize {
+        let positions = dividers.iter().enumerate().map(|(i, divider)| {
+            let order = |x: &&Packet| x.compare(divider, Order::Packet);
+            let before = dividers[..i].iter().filter(|x| order(x).is_le()).count();
+            let after = dividers[i + 1..]
+                .iter()
+                .filter(|x| order(x).is_lt())
+                .count();
+            self.rank(divider) + before + after + 1
+        });
+        positions.product()
+    }
+
+    // One packet per line with its position, the marked ones get a note
+    pub fn listing(&self, marked: &[Packet]) -> String {
+        let lines = self.sorted().into_iter().enumerate().map(|(i, packet)| {
+            let note = if marked.contains(packet) {
+                "  <- divider"
+            } else {
+                ""
+            };
+            format!("{:>4}  {}{}", i + 1, packet, note)
+        });
+        lines.collect::<Vec<_>>().join("\n")
+    }
+}
+
+impl FromIterator<Packet> for PacketStore {
+    fn from_iter<T: IntoIterator<Item = Packet>>(iter: T) -> Self {
+        PacketStore {
+            packets: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::PacketStore;
+    use aoc_common::Packet;
+
+    fn packets(texts: &[&str]) -> Vec<Packet> {
+        texts.iter().map(|x| x.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn ranks_and_decoder_key() {
+        let store: PacketStore = packets(&["[[1],4]", "[]", "[3]", "[[[]]]", "[1,1,3,1,1]"])
+            .into_iter()
+            .collect();
+        let listed: Vec<String> = store.sorted().iter().map(|x| x.to_string()).collect();
+        assert_eq!(listed, ["[]", "[[[]]]", "[1,1,3,1,1]", "[[1],4]", "[3]"]);
+
+        let dividers = packets(&["[[2]]", "[[6]]"]);
+        assert_eq!(store.rank(&dividers[0]), 4);
+        assert_eq!(store.rank(&dividers[1]), 5);
+        assert_eq!(store.decoder_key(&dividers), 5 * 7);
+        // the same in the other order, and a divider equal to a packet goes after it
+        let reversed = packets(&["[[6]]", "[[2]]"]);
+        assert_eq!(store.decoder_key(&reversed), 7 * 5);
+        assert_eq!(store.decoder_key(&packets(&["[[3]]"])), 6);
+
+        let mut store = store;
+        assert_eq!(store.insert(dividers[0].clone()), 4);
+        assert_eq!(store.sorted()[4], &dividers[0]);
+        assert_eq!(
+            store.listing(&dividers).lines().nth(4),
+            Some("   5  [[2]]  <- divider")
+        );
+    }
+
+    #[test]
+    fn equal_packets_keep_their_order() {
+        // [1] and [[1]] are equal in the puzzle order
+        let equal = packets(&["[[1]]", "[2]", "[1]"]);
+        let collected: PacketStore = equal.iter().cloned().collect();
+        let listed: Vec<String> = collected.sorted().iter().map(|x| x.to_string()).collect();
+        assert_eq!(listed, ["[[1]]", "[1]", "[2]"]);
+        let mut inserted = PacketStore::new();
+        let ranks: Vec<usize> = equal.into_iter().map(|x| inserted.insert(x)).collect();
+        assert_eq!(ranks, [0, 1, 1]);
+        assert_eq!(inserted.sorted(), collected.sorted());
+    }
+}
@@ -0,0 +1,7 @@
+use crate::{default_dividers, read_packets};
+use aoc_common::ParseResult;
+
+pub fn part_two(input: &str) -> ParseResult<usize> {
+    let store = read_packets(input)?;
+    Ok(store.decoder_key(&default_dividers()))
+}
@@ -1,190 +1,83 @@
-use std::{
-    fmt::Debug,
-    hash::Hash,
-    io,
-    ops::{Add, Sub},
-};
-
-#[derive(Debug, PartialEq, Eq)]
+use aoc_common::{numbered_lines, Cells, Cursor, Grid, ParseResult, Vector};
+use std::any::Any;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 enum Tile {
     Air,
     Rock,
     Sand,
+    #[allow(dead_code)]
     Debug,
 }
 
 struct Cave {
     start: Vector,
-    grid: Vec<Vec<Tile>>,
+    grid: Grid<Tile>,
 }
 
 impl Cave {
     fn new(width: usize, height: usize) -> Cave {
-        let mut grid = vec![];
-        for _ in 0..height {
-            let mut row = vec![];
-            for _ in 0..width {
-                row.push(Tile::Air);
-            }
-            grid.push(row);
-        }
-
         Cave {
-            grid,
+            grid: Grid::new(width, height, Tile::Air),
             start: Vector::zero(),
         }
     }
 
+    #[allow(dead_code)]
     fn shape(&self) -> (usize, usize) {
-        (self.grid[0].len(), self.grid.len())
+        (self.grid.width(), self.grid.height())
     }
 
     fn emit(&self) -> Vector {
-        self.start.clone()
+        self.start
     }
 
+    #[allow(dead_code)]
     fn is_out(&self, tail: &Vector) -> bool {
-        if tail.x < 0 {
-            return true
-        }
-
-        let last_x = self.grid[0].len() as isize;
-        if tail.x > last_x - 1 {
-            return true
-        }
-
-        if tail.y < 0 {
-            return true
-        }
-
-        let last_y = self.grid.len() as isize;
-        tail.y > last_y - 1
+        !self.grid.in_bounds(*tail)
     }
 
     fn is_air(&self, tail: &Vector) -> bool {
-        let x = tail.x as usize;
-        let y = tail.y as usize;
-        let t = &self.grid[y][x];
-        match t {
-            Tile::Air => true,
-            _ => false,
-        }
+        matches!(self.grid[*tail], Tile::Air)
     }
 
     fn mark_tail(&mut self, tail: &Vector, t: Tile) {
-        let x = tail.x as usize;
-        let y = tail.y as usize;
-        self.grid[y][x] = t;
+        self.grid[*tail] = t;
     }
 
     fn draw(&mut self, from: &Vector, to: &Vector) {
-        let mut cursor = from.clone();
+        let mut cursor = *from;
         let mut step = *to - *from;
         step.norm();
         let length = 1 + from.dist_to(to);
         for _ in 0..length {
-            let x = cursor.x as usize;
-            let y = cursor.y as usize;
-            self.grid[y][x] = Tile::Rock;
+            self.grid[cursor] = Tile::Rock;
 
             cursor = cursor + step;
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Vector {
-    x: isize,
-    y: isize,
-}
-
-impl Vector {
-    fn zero() -> Self {
-        Vector { x: 0, y: 0 }
-    }
-
-    fn max() -> Self {
-        Vector {
-            x: std::isize::MAX,
-            y: std::isize::MAX,
-        }
-    }
-
-    fn min() -> Self {
-        Vector {
-            x: std::isize::MIN,
-            y: std::isize::MIN,
-        }
-    }
-
-    fn dist_to(&self, other: &Self) -> u32 {
-        if self.x == other.x {
-            (self.y as i32 - other.y as i32).abs() as u32
-        } else {
-            (self.x as i32 - other.x as i32).abs() as u32
-        }
-    }
-
-    fn norm(&mut self) {
-        if self.x > 0 {
-            self.x = 1;
-        } else if self.x < 0 {
-            self.x = -1;
-        }
-        if self.y > 0 {
-            self.y = 1;
-        } else if self.y < 0 {
-            self.y = -1;
-        }
-    }
-}
-
-impl Sub for Vector {
-    type Output = Self;
-
-    fn sub(self, other: Self) -> Self::Output {
-        Vector {
-            x: self.x - other.x,
-            y: self.y - other.y,
-        }
-    }
-}
-
-impl Add for Vector {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Vector {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
-    }
-}
-
-fn parse_row(row: &str) -> Vec<Vector> {
-    let parts: Vec<&str> = row.split(" -> ").collect();
+fn parse_row(number: usize, row: &str) -> ParseResult<Vec<Vector>> {
+    let mut cursor = Cursor::new(number, row);
     let mut coords = vec![];
-    for value in parts {
-        let xy: Vec<&str> = value.split(',').collect();
-        let x = xy[0].parse::<isize>().unwrap();
-        let y = xy[1].parse::<isize>().unwrap();
+    loop {
+        let x = cursor.number::<isize>()?;
+        cursor.tag(",")?;
+        let y = cursor.number::<isize>()?;
         coords.push(Vector { x, y });
+        if cursor.is_end() {
+            return Ok(coords);
+        }
+        cursor.tag(" -> ")?;
     }
-    coords
 }
 
-fn read_input() -> Cave {
+fn read_input(input: &str) -> ParseResult<Cave> {
     let mut paths = vec![];
-    for line in io::stdin().lines() {
-        match line {
-            Err(error) => {
-                panic!("{}", error);
-            }
-            Ok(value) => {
-                let path = parse_row(value.as_str());
-                paths.push(path);
-            }
-        }
+    for (number, line) in numbered_lines(input) {
+        let path = parse_row(number, line)?;
+        paths.push(path);
     }
 
     let mut tl = Vector::max();
@@ -192,10 +85,10 @@ fn read_input() -> Cave {
 
     let emit = Vector { x: 500, y: 0 };
 
-    let mut coords = vec![emit.clone()];
+    let mut coords = vec![emit];
     for path in &paths {
         for coord in path {
-            coords.push(coord.clone());
+            coords.push(*coord);
         }
     }
 
@@ -230,11 +123,16 @@ fn read_input() -> Cave {
         }
     }
 
-    cave
+    Ok(cave)
+}
+
+pub fn parse(input: &str) -> ParseResult<Box<dyn Any>> {
+    Ok(Box::new(read_input(input)?))
 }
 
+#[allow(dead_code)]
 fn print_cave(cave: &Cave) {
-    for (y, row) in cave.grid.iter().enumerate() {
+    for (y, row) in cave.grid.rows().enumerate() {
         for (x, tile) in row.iter().enumerate() {
             let cell = Vector {
                 x: x as isize,
@@ -251,13 +149,12 @@ fn print_cave(cave: &Cave) {
             }
             print!("{}", marker);
         }
-        println!("");
+        println!();
     }
 }
 
-fn main() {
-    let mut cave = read_input();
-    println!("Shape: {:?}", cave.shape());
+pub fn part_one(input: &str) -> ParseResult<u32> {
+    let mut cave = read_input(input)?;
 
     let down = Vector { x: 0, y: 1 };
     let left = Vector { x: -1, y: 1 };
@@ -277,7 +174,6 @@ fn main() {
 
             next_sand = sand + left;
             if cave.is_out(&next_sand) {
-                println!("stop left {:?}", next_sand);
                 // cave.mark_tail(&next_sand, Tile::Debug);
                 break 'sand;
             } else if cave.is_air(&next_sand) {
@@ -287,7 +183,6 @@ fn main() {
 
             next_sand = sand + right;
             if cave.is_out(&next_sand) {
-                println!("stop right {:?}", next_sand);
                 // cave.mark_tail(&next_sand, Tile::Debug);
                 break 'sand;
             } else if cave.is_air(&next_sand) {
@@ -301,13 +196,12 @@ fn main() {
         }
     }
 
-    print_cave(&cave);
-    println!("Result: {}", count);
+    Ok(count)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse_row, Vector};
+    use crate::one::{parse_row, Vector};
 
     #[test]
     fn vector_norm_1() {
@@ -353,7 +247,7 @@ mod tests {
 
     #[test]
     fn parse_row_of_four_coords() {
-        let result = parse_row("503,4 -> 502,4 -> 502,9 -> 494,9");
+        let result = parse_row(1, "503,4 -> 502,4 -> 502,9 -> 494,9").unwrap();
         assert_eq!(
             result,
             vec![
@@ -0,0 +1,37 @@
+use aoc_common::{Answer, ParseResult, Solution};
+use std::any::Any;
+
+mod one;
+mod two;
+
+// The example asks about a different row and a smaller search area than
+// the puzzle input
+pub struct Day15 {
+    pub row: isize,
+    pub search_size: isize,
+}
+
+impl Day15 {
+    pub const PUZZLE: Day15 = Day15 {
+        row: 2000000,
+        search_size: 4000000,
+    };
+    pub const EXAMPLE: Day15 = Day15 {
+        row: 10,
+        search_size: 20,
+    };
+}
+
+impl Solution for Day15 {
+    fn parse(&self, input: &str) -> Option<ParseResult<Box<dyn Any>>> {
+        Some(one::parse(input))
+    }
+
+    fn part_one(&self, input: &str) -> ParseResult<Answer> {
+        Ok(one::part_one(input, self.row)?.into())
+    }
+
+    fn part_two(&self, input: &str) -> ParseResult<Answer> {
+        Ok(two::part_two(input, self.search_size)?.into())
+    }
+}
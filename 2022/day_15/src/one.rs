@@ -0,0 +1,102 @@
+use aoc_common::{numbered_lines, Cursor, IntervalSet, ParseResult, Vector};
+use std::{any::Any, iter::zip, vec};
+
+fn parse_position(cursor: &mut Cursor) -> ParseResult<Vector> {
+    cursor.tag("x=")?;
+    let x = cursor.number::<isize>()?;
+    cursor.tag(", y=")?;
+    let y = cursor.number::<isize>()?;
+    Ok(Vector { x, y })
+}
+
+fn parse_row(number: usize, row: &str) -> ParseResult<(Vector, Vector)> {
+    // Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+    let mut cursor = Cursor::new(number, row);
+    cursor.tag("Sensor at ")?;
+    let sensor = parse_position(&mut cursor)?;
+    cursor.tag(": closest beacon is at ")?;
+    let beacon = parse_position(&mut cursor)?;
+    cursor.end()?;
+    Ok((sensor, beacon))
+}
+
+fn read_input(input: &str) -> ParseResult<(Vec<Vector>, Vec<Vector>)> {
+    let mut sensors = vec![];
+    let mut beacons = vec![];
+    for (number, line) in numbered_lines(input) {
+        let (s, b) = parse_row(number, line)?;
+        sensors.push(s);
+        beacons.push(b);
+    }
+    Ok((sensors, beacons))
+}
+
+pub fn parse(input: &str) -> ParseResult<Box<dyn Any>> {
+    Ok(Box::new(read_input(input)?))
+}
+
+fn manhattan(x: &Vector, y: &Vector) -> isize {
+    // Take the sum of the absolute values of the differences of the coordinates.
+    // For example, if x=(a,b) and y=(c,d), the Manhattan distance between x and y is
+    // |a-c| + |b-d|
+
+    let (a, b) = x.as_tuple();
+    let (c, d) = y.as_tuple();
+
+    (a - c).abs() + (b - d).abs()
+}
+
+// Positions of the row within reach of a sensor, a sensor reaches
+// less of a row the further away the row is
+fn covered_on_row(sensors: &[Vector], beacons: &[Vector], y: isize) -> IntervalSet {
+    zip(sensors, beacons)
+        .filter_map(|(sensor, beacon)| {
+            let reach = manhattan(sensor, beacon) - (sensor.y - y).abs();
+            let x = sensor.x as i64;
+            (reach >= 0).then(|| x - reach as i64..x + reach as i64 + 1)
+        })
+        .collect()
+}
+
+fn no_beacon_count(sensors: &[Vector], beacons: &[Vector], y: isize) -> i64 {
+    let mut covered = covered_on_row(sensors, beacons, y);
+    for beacon in beacons.iter().filter(|beacon| beacon.y == y) {
+        covered.remove(beacon.x as i64..beacon.x as i64 + 1);
+    }
+    covered.total_length()
+}
+
+pub fn part_one(input: &str, y: isize) -> ParseResult<i64> {
+    let (sensors, beacons) = read_input(input)?;
+    Ok(no_beacon_count(&sensors, &beacons, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::one::{
+        covered_on_row, manhattan, no_beacon_count, parse_row, part_one, read_input, Vector,
+    };
+
+    #[test]
+    fn parse_row_from_example() {
+        let result = parse_row(1, "Sensor at x=2, y=18: closest beacon is at x=-2, y=15").unwrap();
+        assert_eq!(result, (Vector::new(2, 18), Vector::new(-2, 15)));
+    }
+
+    #[test]
+    fn manhattan9() {
+        let dist = manhattan(&Vector::new(8, 7), &Vector::new(2, 10));
+        assert_eq!(dist, 9);
+    }
+
+    #[test]
+    fn row_10_of_the_example() {
+        let (sensors, beacons) = read_input(include_str!("../test.txt")).unwrap();
+        assert_eq!(
+            covered_on_row(&sensors, &beacons, 10).ranges().to_vec(),
+            vec![-2..25]
+        );
+        assert_eq!(no_beacon_count(&sensors, &beacons, 10), 26);
+        assert_eq!(part_one(include_str!("../test.txt"), 10), Ok(26));
+    }
+}
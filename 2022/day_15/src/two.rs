@@ -0,0 +1,171 @@
+use aoc_common::{
+    diamond::unrotate, numbered_lines, Cursor, Diamond, Line, ParseError, ParseResult, Rect, Vector,
+};
+use std::{iter::zip, vec};
+
+fn parse_position(cursor: &mut Cursor) -> ParseResult<Vector> {
+    cursor.tag("x=")?;
+    let x = cursor.number::<isize>()?;
+    cursor.tag(", y=")?;
+    let y = cursor.number::<isize>()?;
+    Ok(Vector { x, y })
+}
+
+fn parse_row(number: usize, row: &str) -> ParseResult<(Vector, Vector)> {
+    // Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+    let mut cursor = Cursor::new(number, row);
+    cursor.tag("Sensor at ")?;
+    let sensor = parse_position(&mut cursor)?;
+    cursor.tag(": closest beacon is at ")?;
+    let beacon = parse_position(&mut cursor)?;
+    cursor.end()?;
+    Ok((sensor, beacon))
+}
+
+fn read_input(input: &str) -> ParseResult<(Vec<Vector>, Vec<Vector>)> {
+    let mut sensors = vec![];
+    let mut beacons = vec![];
+    for (number, line) in numbered_lines(input) {
+        let (s, b) = parse_row(number, line)?;
+        sensors.push(s);
+        beacons.push(b);
+    }
+    Ok((sensors, beacons))
+}
+
+fn manhattan(x: &Vector, y: &Vector) -> isize {
+    // Take the sum of the absolute values of the differences of the coordinates.
+    // For example, if x=(a,b) and y=(c,d), the Manhattan distance between x and y is
+    // |a-c| + |b-d|
+
+    let (a, b) = x.as_tuple();
+    let (c, d) = y.as_tuple();
+
+    (a - c).abs() + (b - d).abs()
+}
+
+fn is_uncovered(diamonds: &[Diamond], point: &Vector, min: Vector, max: Vector) -> bool {
+    (min.x..=max.x).contains(&point.x)
+        && (min.y..=max.y).contains(&point.y)
+        && !diamonds.iter().any(|diamond| diamond.contains(point))
+}
+
+// Subtracts every diamond from the search area in rotated coordinates,
+// whatever is left over is not covered
+fn find_by_subtraction(diamonds: &[Diamond], min: Vector, max: Vector) -> Option<Vector> {
+    let area = Rect::new(
+        Vector::new(min.x + min.y, min.x - max.y),
+        Vector::new(max.x + max.y, max.x - min.y),
+    );
+    let mut left = vec![area];
+    for diamond in diamonds {
+        let rect = diamond.rect();
+        left = left.iter().flat_map(|x| x.subtract(&rect)).collect();
+    }
+    left.iter().find_map(|rect| rect.point_within(min, max))
+}
+
+// A single uncovered position has diamonds on all four sides of it, so it is
+// where the gap line between two diamonds crosses the gap line between two
+// others. Only on the edge of the search area the edge stands in for a diamond
+fn find_distress_beacon(diamonds: &[Diamond], x_max: isize, y_max: isize) -> Option<Vector> {
+    let (min, max) = (Vector::zero(), Vector::new(x_max, y_max));
+    let mut sums = vec![];
+    let mut differences = vec![];
+    for (i, a) in diamonds.iter().enumerate() {
+        for b in &diamonds[i + 1..] {
+            match a.gap(b) {
+                Some(Line::Sum(value)) => sums.push(value),
+                Some(Line::Difference(value)) => differences.push(value),
+                None => {}
+            }
+        }
+    }
+
+    let mut crossings = sums.iter().flat_map(|sum| {
+        differences
+            .iter()
+            .filter_map(|difference| unrotate(Vector::new(*sum, *difference)))
+    });
+    crossings
+        .find(|point| is_uncovered(diamonds, point, min, max))
+        .or_else(|| find_by_subtraction(diamonds, min, max))
+}
+
+fn read_diamonds(input: &str) -> ParseResult<Vec<Diamond>> {
+    let (sensors, beacons) = read_input(input)?;
+    Ok(zip(&sensors, &beacons)
+        .map(|(sensor, beacon)| Diamond::new(*sensor, manhattan(sensor, beacon)))
+        .collect())
+}
+
+// The distress beacon is somewhere in 0..=size on both axes
+pub fn part_two(input: &str, size: isize) -> ParseResult<i64> {
+    let diamonds = read_diamonds(input)?;
+    let beacon = find_distress_beacon(&diamonds, size, size).ok_or_else(|| {
+        let expected = format!(
+            "sensors that leave a position within 0..={} uncovered",
+            size
+        );
+        ParseError::end_of_input(input.lines().count() + 1, &expected)
+    })?;
+    let x = beacon.x as i64;
+    let y = beacon.y as i64;
+    Ok(x * 4000000 + y)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::two::{
+        find_by_subtraction, find_distress_beacon, is_uncovered, manhattan, parse_row, part_two,
+        read_diamonds, Vector,
+    };
+    use aoc_common::{Diamond, ParseError};
+
+    #[test]
+    fn parse_row_from_example() {
+        let result = parse_row(1, "Sensor at x=2, y=18: closest beacon is at x=-2, y=15").unwrap();
+        assert_eq!(result, (Vector::new(2, 18), Vector::new(-2, 15)));
+    }
+
+    #[test]
+    fn manhattan9() {
+        let dist = manhattan(&Vector::new(8, 7), &Vector::new(2, 10));
+        assert_eq!(dist, 9);
+    }
+
+    #[test]
+    fn distress_beacon_of_the_example() {
+        let diamonds = read_diamonds(include_str!("../test.txt")).unwrap();
+        let beacon = Some(Vector::new(14, 11));
+        assert_eq!(find_distress_beacon(&diamonds, 20, 20), beacon);
+        assert_eq!(
+            find_by_subtraction(&diamonds, Vector::zero(), Vector::new(20, 20)),
+            beacon
+        );
+        assert_eq!(part_two(include_str!("../test.txt"), 20), Ok(56000011));
+    }
+
+    #[test]
+    fn beacon_on_the_edge() {
+        // covers all of 0..=4 but the corner at 4, 4
+        let diamonds = [
+            Diamond::new(Vector::new(1, 1), 5),
+            Diamond::new(Vector::new(4, 0), 3),
+            Diamond::new(Vector::new(0, 4), 3),
+        ];
+        assert_eq!(
+            find_distress_beacon(&diamonds, 4, 4),
+            Some(Vector::new(4, 4))
+        );
+        assert_eq!(find_distress_beacon(&diamonds, 3, 3), None);
+        let input = "Sensor at x=5, y=5: closest beacon is at x=5, y=35";
+        let expected = "sensors that leave a position within 0..=20 uncovered";
+        assert_eq!(
+            part_two(input, 20),
+            Err(ParseError::end_of_input(2, expected))
+        );
+        let (min, max) = (Vector::zero(), Vector::new(4, 4));
+        assert!(is_uncovered(&diamonds, &Vector::new(4, 4), min, max));
+    }
+}
@@ -0,0 +1,297 @@
+use aoc_common::{search, Cursor, Graph, Mode, ParseError, ParseResult, Problem};
+use std::{
+    any::Any,
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+};
+
+const MINUTES: i32 = 30;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Valve {
+    id: usize,
+    name: String,
+    rate: i32,
+    tunnels: HashSet<String>,
+}
+
+// valves are the nodes of the tunnel graph, known by their id
+type NodeIndex = usize;
+
+#[derive(Debug, Clone)]
+struct System {
+    // steps between every two valves
+    distances: HashMap<(NodeIndex, NodeIndex), i32>,
+    nodes: HashMap<String, NodeIndex>,
+    valves: HashMap<NodeIndex, Valve>,
+}
+
+impl System {
+    fn new(valves: &[Valve]) -> System {
+        let mut graph = Graph::<NodeIndex, i32>::new();
+        for v in valves {
+            graph.add_node(v.id, v.rate);
+        }
+        let nodes: HashMap<String, NodeIndex> =
+            HashMap::from_iter(valves.iter().map(|v| (v.name.clone(), v.id)));
+        for v in valves {
+            for tunnel in &v.tunnels {
+                graph.add_edge(v.id, nodes[tunnel], 1);
+            }
+        }
+        let valves = HashMap::from_iter(valves.iter().map(|v| (v.id, v.clone())));
+
+        Self {
+            distances: graph.floyd_warshall(),
+            nodes,
+            valves,
+        }
+    }
+
+    fn node_id(&self, name: &String) -> NodeIndex {
+        *self.nodes.get(name).unwrap()
+    }
+
+    fn get_valve(&self, id: &NodeIndex) -> &Valve {
+        self.valves.get(id).unwrap()
+    }
+
+    fn get_shortest_path(&self, start: NodeIndex, next: NodeIndex) -> i32 {
+        self.distances[&(start, next)]
+    }
+
+    fn solve_from(&self, start: &String, time_left: i32) -> i32 {
+        let start_id = self.node_id(start);
+        let available_nodes = HashSet::from_iter(
+            self.valves
+                .iter()
+                .filter(|(_, valve)| valve.rate > 0)
+                .map(|(id, _)| *id),
+        );
+
+        self.best_flow(start_id, time_left, &available_nodes)
+    }
+
+    fn best_flow(
+        &self,
+        from_node: NodeIndex,
+        time_left: i32,
+        available_nodes: &HashSet<NodeIndex>,
+    ) -> i32 {
+        let tour = Tour {
+            system: self,
+            targets: available_nodes
+                .iter()
+                .filter(|n| **n != from_node && self.get_valve(n).rate > 0)
+                .copied()
+                .collect(),
+        };
+
+        // Spend a minute to open the first valve if it has flow:
+        let rate = self.get_valve(&from_node).rate;
+        let time_left = if rate > 0 { time_left - 1 } else { time_left };
+        let start = Position {
+            node: from_node,
+            time_left,
+            opened: 0,
+            released: rate * time_left.max(0),
+        };
+        search(&tour, start, Mode::BranchAndBound).score
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Position {
+    node: NodeIndex,
+    time_left: i32,
+    // bit i is set when targets[i] is open
+    opened: u64,
+    released: i32,
+}
+
+// Opens the target valves one after another, every valve releases its
+// pressure from the moment it's open until the time runs out
+struct Tour<'a> {
+    system: &'a System,
+    targets: Vec<NodeIndex>,
+}
+
+impl Tour<'_> {
+    // time left after walking to the target and opening it
+    fn time_after(&self, position: &Position, target: NodeIndex) -> i32 {
+        position.time_left - self.system.get_shortest_path(position.node, target) - 1
+    }
+}
+
+impl Problem for Tour<'_> {
+    type State = Position;
+    type Key = Position;
+    type Score = i32;
+
+    fn successors(&self, position: &Position, next: &mut Vec<Position>) {
+        for (i, target) in self.targets.iter().enumerate() {
+            let time_left = self.time_after(position, *target);
+            if position.opened & 1 << i != 0 || time_left <= 0 {
+                continue;
+            }
+            next.push(Position {
+                node: *target,
+                time_left,
+                opened: position.opened | 1 << i,
+                released: position.released + self.system.get_valve(target).rate * time_left,
+            });
+        }
+    }
+
+    fn score(&self, position: &Position) -> i32 {
+        position.released
+    }
+
+    // as if every closed valve was the next one to open
+    fn upper_bound(&self, position: &Position) -> i32 {
+        let mut bound = position.released;
+        for (i, target) in self.targets.iter().enumerate() {
+            let time_left = self.time_after(position, *target);
+            if position.opened & 1 << i == 0 && time_left > 0 {
+                bound += self.system.get_valve(target).rate * time_left;
+            }
+        }
+        bound
+    }
+
+    fn key(&self, position: &Position) -> Position {
+        *position
+    }
+}
+
+// Valves are numbered by their line, the line number is the id plus one
+fn parse_row(row: &str, id: usize) -> ParseResult<Valve> {
+    // Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+    // Valve HH has flow rate=22; tunnel leads to valve GG
+    let mut cursor = Cursor::new(id + 1, row);
+    cursor.tag("Valve ")?;
+    let name = cursor.word()?;
+    cursor.tag(" has flow rate=")?;
+    let rate = cursor.number::<i32>()?;
+    cursor.one_of(
+        "\"; tunnels lead to valves\"",
+        &[
+            ("; tunnels lead to valves ", ()),
+            ("; tunnel leads to valve ", ()),
+        ],
+    )?;
+
+    let mut tunnels = HashSet::<String>::new();
+    loop {
+        tunnels.insert(String::from(cursor.word()?));
+        if cursor.is_end() {
+            break;
+        }
+        cursor.tag(", ")?;
+    }
+
+    Ok(Valve {
+        id,
+        name: String::from(name),
+        rate,
+        tunnels,
+    })
+}
+
+fn read_input(input: &str) -> ParseResult<Vec<Valve>> {
+    let valves = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_row(line, i))
+        .collect::<ParseResult<Vec<Valve>>>()?;
+
+    // every tunnel has to lead to a valve of the input
+    let names = HashSet::<&String>::from_iter(valves.iter().map(|v| &v.name));
+    for (valve, line) in valves.iter().zip(input.lines()) {
+        for tunnel in &valve.tunnels {
+            if !names.contains(tunnel) {
+                let column = line.rfind(tunnel.as_str()).unwrap_or(0) + 1;
+                let found = format!("\"{}\"", tunnel);
+                let number = valve.id + 1;
+                return Err(ParseError::new(number, column, "a known valve", &found));
+            }
+        }
+    }
+    // the valves are opened starting from AA
+    if !names.contains(&"AA".to_string()) {
+        return Err(ParseError::end_of_input(valves.len() + 1, "a valve \"AA\""));
+    }
+    Ok(valves)
+}
+
+pub fn parse(input: &str) -> ParseResult<Box<dyn Any>> {
+    Ok(Box::new(read_input(input)?))
+}
+
+pub fn part_one(input: &str) -> ParseResult<i32> {
+    let items = read_input(input)?;
+    let sys = System::new(&items);
+    Ok(sys.solve_from(&"AA".to_string(), MINUTES))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::ParseError;
+    use std::collections::HashSet;
+
+    use crate::one::{parse_row, read_input, Valve};
+
+    #[test]
+    fn parse_row_with_many_tunnels() {
+        let result = parse_row(
+            "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB",
+            0,
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            Valve {
+                id: 0,
+                name: "AA".to_string(),
+                rate: 0,
+                tunnels: HashSet::from_iter(
+                    ["DD".to_string(), "II".to_string(), "BB".to_string(),]
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn read_input_with_unknown_tunnel() {
+        let input = "Valve AA has flow rate=0; tunnels lead to valves BB, CC\n\
+                     Valve BB has flow rate=3; tunnel leads to valve AA\n";
+        let error = read_input(input).unwrap_err();
+        assert_eq!(error, ParseError::new(1, 54, "a known valve", "\"CC\""));
+
+        let error = parse_row("Valve AA has flow rate=x", 0).unwrap_err();
+        assert_eq!(error.column, 24);
+    }
+
+    #[test]
+    fn empty_input() {
+        let error = ParseError::end_of_input(1, "a valve \"AA\"");
+        assert_eq!(read_input("").unwrap_err(), error);
+        let input = "Valve BB has flow rate=3; tunnel leads to valve BB";
+        let error = ParseError::end_of_input(2, "a valve \"AA\"");
+        assert_eq!(read_input(input).unwrap_err(), error);
+    }
+
+    #[test]
+    fn parse_row_with_single_tunnel() {
+        let result = parse_row("Valve HH has flow rate=22; tunnel leads to valve GG", 0).unwrap();
+        assert_eq!(
+            result,
+            Valve {
+                id: 0,
+                name: "HH".to_string(),
+                rate: 22,
+                tunnels: HashSet::from_iter(["GG".to_string()]),
+            }
+        );
+    }
+}
@@ -1,9 +1,7 @@
-use std::{
-    fmt::Debug,
-    io,
-    vec,
-};
+use aoc_common::{Cursor, ParseResult};
+use std::{any::Any, fmt::Debug, vec};
 
+#[derive(Clone, Copy)]
 enum Jet {
     Left,
     Right,
@@ -59,7 +57,7 @@ impl Shape {
         iter
     }
 
-    fn is_overlap(&self, grid: &Vec<Vec<u8>>) -> bool {
+    fn is_overlap(&self, grid: &[Vec<u8>]) -> bool {
         for (x, y) in self.iter_pixels() {
             if y < 0 || y >= grid.len() as i32 {
                 return true;
@@ -113,34 +111,34 @@ fn get_shapes() -> Vec<Shape> {
     ]
 }
 
-fn read_input() -> Vec<Jet> {
-    let line = io::stdin().lines().next().unwrap();
-    match line {
-        Err(error) => {
-            panic!("{}", error);
+fn read_input(input: &str) -> ParseResult<Vec<Jet>> {
+    let line = input.lines().next().unwrap_or("");
+    let mut cursor = Cursor::new(1, line);
+    let mut jets = vec![];
+    loop {
+        let jet = cursor.one_of("\"<\" or \">\"", &[("<", Jet::Left), (">", Jet::Right)])?;
+        jets.push(jet);
+        if cursor.is_end() {
+            return Ok(jets);
         }
-        Ok(value) => value
-            .chars()
-            .map(|char| match char {
-                '<' => Jet::Left,
-                '>' => Jet::Right,
-                _ => panic!("Wrong char"),
-            })
-            .collect(),
     }
 }
 
+pub fn parse(input: &str) -> ParseResult<Box<dyn Any>> {
+    Ok(Box::new(read_input(input)?))
+}
+
 fn get_line() -> Vec<u8> {
     vec![0, 0, 0, 0, 0, 0, 0]
 }
 
-fn draw_shape(shape: &Shape, grid: &mut Vec<Vec<u8>>) {
+fn draw_shape(shape: &Shape, grid: &mut [Vec<u8>]) {
     for (x, y) in shape.iter_pixels() {
         grid[y as usize][x as usize] = 1;
     }
 }
 
-fn get_most_top(grid: &Vec<Vec<u8>>) -> i32 {
+fn get_most_top(grid: &[Vec<u8>]) -> i32 {
     for (i, row) in grid.iter().rev().enumerate() {
         let y = grid.len() - i;
         for value in row {
@@ -152,7 +150,8 @@ fn get_most_top(grid: &Vec<Vec<u8>>) -> i32 {
     0
 }
 
-fn display_grid(grid: &Vec<Vec<u8>>, shape: Option<&Shape>) {
+#[allow(dead_code)]
+fn display_grid(grid: &[Vec<u8>], shape: Option<&Shape>) {
     for (i, row) in grid.iter().rev().enumerate() {
         let y = grid.len() - i - 1;
         print!("{:0>4} |", y);
@@ -162,12 +161,9 @@ fn display_grid(grid: &Vec<Vec<u8>>, shape: Option<&Shape>) {
                 1 => '#',
                 _ => ' ',
             };
-            match shape {
-                None => {}
-                Some(shape) => {
-                    if shape.include(x as i32, y as i32) {
-                        pixel = '@';
-                    }
+            if let Some(shape) = shape {
+                if shape.include(x as i32, y as i32) {
+                    pixel = '@';
                 }
             }
             print!("{}", pixel);
@@ -178,10 +174,10 @@ fn display_grid(grid: &Vec<Vec<u8>>, shape: Option<&Shape>) {
     println!("     +-------+");
 }
 
-fn main() {
+pub fn part_one(input: &str) -> ParseResult<i32> {
     let shapes = get_shapes();
     let mut shape_cycle = shapes.iter().cycle();
-    let jets = read_input();
+    let jets = read_input(input)?;
     let mut jet_cycle = jets.iter().cycle();
 
     // 7 |-------|
@@ -274,10 +270,5 @@ fn main() {
         }
     }
 
-    // display
-    println!("Final:");
-    display_grid(&grid, None);
-
-    let top = get_most_top(&grid);
-    println!("Result: {}", top);
+    Ok(get_most_top(&grid))
 }
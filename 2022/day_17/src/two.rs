@@ -1,8 +1,7 @@
-use std::{collections::HashMap, fmt::Debug, io, vec};
+use aoc_common::{Cursor, CycleFinder, ParseResult};
+use std::{fmt::Debug, vec};
 
-use moveslice::Moveslice;
-
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 enum Jet {
     Left,
     Right,
@@ -26,20 +25,16 @@ struct Grid {
 
 impl Grid {
     fn new(grid: Option<[u8; 60]>) -> Grid {
-        let grid = match grid {
-            None => [
-                0b1111111, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
-                0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
-                0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
-                0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
-                0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
-                0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
-                0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
-                0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
-                0b0000000, 0b0000000, 0b0000000, 0b0000000,
-            ],
-            Some(grid) => grid,
-        };
+        let grid = grid.unwrap_or([
+            0b1111111, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
+            0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
+            0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
+            0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
+            0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
+            0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
+            0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
+            0b0000000, 0b0000000, 0b0000000, 0b0000000,
+        ]);
         Grid {
             grid,
             high_index: 0,
@@ -56,7 +51,7 @@ impl Grid {
         self.shift += 10;
 
         // 0. move bottom part of grid down one by one
-        self.grid.moveslice(10.., 0);
+        self.grid.rotate_left(10);
 
         // 1. clear top of the grid
         self.grid[50..].fill(0);
@@ -67,7 +62,7 @@ impl Grid {
 
     fn draw_shape(&mut self, shape: &Shape) {
         for (y, row) in shape.iter_rows() {
-            self.grid[y] = row | self.grid[y];
+            self.grid[y] |= row;
         }
         let top = shape.location.1 + shape.height;
         if top > self.high_index {
@@ -214,20 +209,16 @@ fn get_shapes(right_border: usize) -> Vec<Shape> {
     result
 }
 
-fn read_input() -> Vec<Jet> {
-    let line = io::stdin().lines().next().unwrap();
-    match line {
-        Err(error) => {
-            panic!("{}", error);
+fn read_input(input: &str) -> ParseResult<Vec<Jet>> {
+    let line = input.lines().next().unwrap_or("");
+    let mut cursor = Cursor::new(1, line);
+    let mut jets = vec![];
+    loop {
+        let jet = cursor.one_of("\"<\" or \">\"", &[("<", Jet::Left), (">", Jet::Right)])?;
+        jets.push(jet);
+        if cursor.is_end() {
+            return Ok(jets);
         }
-        Ok(value) => value
-            .chars()
-            .map(|char| match char {
-                '<' => Jet::Left,
-                '>' => Jet::Right,
-                _ => panic!("Wrong char"),
-            })
-            .collect(),
     }
 }
 
@@ -246,22 +237,22 @@ fn drop_rock<'a>(grid: &mut Grid, jets: &mut impl Iterator<Item = &'a Jet>, rock
         match jet {
             Jet::Left => {
                 rock.move_left();
-                if grid.contains(&rock) {
+                if grid.contains(rock) {
                     rock.move_right();
                 }
             }
             Jet::Right => {
                 rock.move_right();
-                if grid.contains(&rock) {
+                if grid.contains(rock) {
                     rock.move_left();
                 }
             }
         }
 
         rock.move_down();
-        if grid.contains(&rock) {
+        if grid.contains(rock) {
             rock.move_up();
-            grid.draw_shape(&rock);
+            grid.draw_shape(rock);
             break;
         }
     }
@@ -273,102 +264,63 @@ fn drop_rock<'a>(grid: &mut Grid, jets: &mut impl Iterator<Item = &'a Jet>, rock
 
 fn solve(jets: Vec<Jet>, rocks: usize) -> Grid {
     let shapes = get_shapes(7);
-    let mut shape_cycle = shapes.iter().cycle();
-    let mut jet_cycle = jets.iter().cycle();
+    let mut shape_cycle = shapes.iter().enumerate().cycle().peekable();
+    let mut jet_cycle = jets.iter().enumerate().cycle().peekable();
 
-    let mut frames = HashMap::new();
-    let mut last_found = 0;
+    // the same top of the tower with the same next shape and jet
+    // lands every later rock the same way
+    let mut frames = CycleFinder::new();
     let mut heights = vec![];
 
     let mut grid = Grid::new(None);
     for index in 0..rocks {
-        let mut rock = shape_cycle.next().unwrap().clone();
-        drop_rock(&mut grid, &mut jet_cycle, &mut rock);
-
-        // save impact of this rock to the tall height
-        heights.push((grid.shift, grid.high_index));
-
-        let frame = frames.get(&grid.grid);
-        match frame {
-            // unique frame -> save it
-            None => {
-                frames.insert(grid.grid, (index, grid.height()));
-            }
-
-            // frame found
-            Some((frame, start_height)) => {
-                if *frame < last_found {
-                    // frame is the start of the group
-                    // so frame-1 is the last value of height before groups were detected
-                    let head_frame = frame - 1;
-                    let head_height = heights[head_frame].0 + heights[head_frame].1;
-
-                    // index (or current iteration) - is the end of the group
-                    let end_height = grid.height();
-                    let group_frame = index - frame;
-                    let group_height = end_height - start_height;
-
-                    let iter_left = rocks - index;
-                    let pool = (rocks - head_frame) / group_frame;
-
-                    let tail_rocks = rocks - head_frame - group_frame * pool;
-
-                    println!(
-                        "REPEAT from frame {} -> {}; group_frame: {}; left {} iters",
-                        frame, index, group_frame, iter_left
-                    );
-                    println!("before_height = {} at frame {}", head_height, head_frame);
-                    println!("start_height: {}", start_height);
-                    println!("end_height: {}", end_height);
-                    println!("group_height: {}", group_height);
-                    println!("groups_pool: {}", pool);
-                    println!("tail_rocks: {}", tail_rocks);
-
-                    grid.shift = head_height + group_height * pool - grid.high_index;
-                    for _ in 0..tail_rocks {
-                        let mut rock = shape_cycle.next().unwrap().clone();
-                        drop_rock(&mut grid, &mut jet_cycle, &mut rock);
-                    }
-
-                    return grid;
-                } else {
-                    last_found = *frame;
-                }
+        let (_, shape) = shape_cycle.next().unwrap();
+        let mut rock = shape.clone();
+        drop_rock(
+            &mut grid,
+            &mut jet_cycle.by_ref().map(|(_, jet)| jet),
+            &mut rock,
+        );
+        heights.push(grid.height() as i64);
+
+        let shape = shape_cycle.peek().unwrap().0;
+        let jet = jet_cycle.peek().unwrap().0;
+        if let Some(cycle) = frames.push((grid.grid, grid.high_index, shape, jet)) {
+            // skip as many whole groups as fit into the rocks left
+            // the rest of rocks are dropped one by one
+            let iter_left = rocks - index - 1;
+            let skipped = index + iter_left / cycle.length * cycle.length;
+            grid.shift += (cycle.extrapolate(&heights, skipped) - heights[index]) as usize;
+            for _ in 0..iter_left % cycle.length {
+                let (_, shape) = shape_cycle.next().unwrap();
+                let mut rock = shape.clone();
+                drop_rock(
+                    &mut grid,
+                    &mut jet_cycle.by_ref().map(|(_, jet)| jet),
+                    &mut rock,
+                );
             }
+            return grid;
         }
     }
     grid
 }
 
-fn main() {
-    // let rocks = 2022;
-    // let rocks = 1_000_000;
-    // let rocks = 1_000_000_000;
-    let rocks = 1_000_000_000_000;
-    // let rocks = 10;
+fn part_two(grid: &Grid) -> usize {
+    grid.height()
+}
 
-    // let rocks = 10437;
+pub fn tower_height(input: &str) -> ParseResult<usize> {
+    let rocks = 1_000_000_000_000;
 
-    let jets = read_input();
+    let jets = read_input(input)?;
     let grid = solve(jets, rocks);
-    let top = grid.height();
-
-    // println!("Result: {} ({})", top, top == 3153);
-    // println!("Result: {} ({})", top, top == 1553686);
-    // println!("Result: {} ({})", top, top == 1553665705);
-    println!("Result: {}", top);
-
-    // for i in grid.grid.iter().rev() {
-    //     if *i == 0 {
-    //         continue;
-    //     }
-    //     println!("{:#09b}", i);
-    // }
+    Ok(part_two(&grid))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{get_shapes, part_two, solve, Grid, Jet, Shape};
+    use crate::two::{get_shapes, part_two, solve, Grid, Jet, Shape};
 
     #[test]
     fn test_1() {
@@ -592,7 +544,7 @@ mod tests {
 
     #[test]
     fn binary_ops() {
-        assert_eq!(0b1111000 >> 0, 0b1111000);
+        assert_eq!(0b1111000, 0b1111000);
         assert_eq!(0b0001111 << 2, 0b0111100);
         assert_eq!(0b0001111 >> 2, 0b0000011);
         assert_eq!(0b0001111 & 0b0011000, 0b0001000);
@@ -648,19 +600,19 @@ mod tests {
         shape.set_location(0, 9);
         assert_eq!(
             shape.iter_rows().collect::<Vec<(usize, u8)>>(),
-            vec![(09, 0b0100000), (10, 0b1110000), (11, 0b0100000),]
+            vec![(9, 0b0100000), (10, 0b1110000), (11, 0b0100000),]
         );
 
         shape.move_right();
         assert_eq!(
             shape.iter_rows().collect::<Vec<(usize, u8)>>(),
-            vec![(09, 0b0010000), (10, 0b0111000), (11, 0b0010000),]
+            vec![(9, 0b0010000), (10, 0b0111000), (11, 0b0010000),]
         );
 
         shape.move_right();
         assert_eq!(
             shape.iter_rows().collect::<Vec<(usize, u8)>>(),
-            vec![(09, 0b0001000), (10, 0b0011100), (11, 0b0001000),]
+            vec![(9, 0b0001000), (10, 0b0011100), (11, 0b0001000),]
         );
 
         shape.move_right();
@@ -668,7 +620,7 @@ mod tests {
         shape.move_right();
         assert_eq!(
             shape.iter_rows().collect::<Vec<(usize, u8)>>(),
-            vec![(09, 0b0000010), (10, 0b0000111), (11, 0b0000010),]
+            vec![(9, 0b0000010), (10, 0b0000111), (11, 0b0000010),]
         );
     }
 
@@ -797,19 +749,19 @@ mod tests {
             ],
         );
         shape.set_location(0, 0);
-        assert_eq!(grid.contains(&shape), true);
+        assert!(grid.contains(&shape));
 
         shape.set_location(2, 1);
-        assert_eq!(grid.contains(&shape), true);
+        assert!(grid.contains(&shape));
 
         shape.set_location(1, 2);
-        assert_eq!(grid.contains(&shape), true);
+        assert!(grid.contains(&shape));
 
         shape.set_location(1, 3);
-        assert_eq!(grid.contains(&shape), false);
+        assert!(!grid.contains(&shape));
 
         shape.set_location(3, 5);
-        assert_eq!(grid.contains(&shape), false);
+        assert!(!grid.contains(&shape));
 
         let mut shape = Shape::new(
             3,
@@ -821,10 +773,10 @@ mod tests {
             ],
         );
         shape.set_location(4, 5);
-        assert_eq!(grid.contains(&shape), false);
+        assert!(!grid.contains(&shape));
 
         shape.set_location(2, 7);
-        assert_eq!(grid.contains(&shape), true);
+        assert!(grid.contains(&shape));
     }
 
     #[test]
@@ -1,8 +1,7 @@
-use std::{fmt::Debug, io, vec};
+use aoc_common::{Cursor, ParseResult};
+use std::{fmt::Debug, vec};
 
-use moveslice::Moveslice;
-
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 enum Jet {
     Left,
     Right,
@@ -26,20 +25,16 @@ struct Grid {
 
 impl Grid {
     fn new(grid: Option<[u8; 60]>) -> Grid {
-        let grid = match grid {
-            None => [
-                0b1111111, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
-                0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
-                0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
-                0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
-                0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
-                0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
-                0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
-                0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
-                0b0000000, 0b0000000, 0b0000000, 0b0000000,
-            ],
-            Some(grid) => grid,
-        };
+        let grid = grid.unwrap_or([
+            0b1111111, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
+            0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
+            0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
+            0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
+            0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
+            0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
+            0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000, 0b0000000,
+            0b0000000, 0b0000000, 0b0000000, 0b0000000,
+        ]);
         Grid {
             grid,
             high_index: 0,
@@ -52,7 +47,7 @@ impl Grid {
         self.shift += 10;
 
         // 0. move bottom part of grid down one by one
-        self.grid.moveslice(10.., 0);
+        self.grid.rotate_left(10);
 
         // 1. clear top of the grid
         self.grid[50..].fill(0);
@@ -63,7 +58,7 @@ impl Grid {
 
     fn draw_shape(&mut self, shape: &Shape) {
         for (y, row) in shape.iter_rows() {
-            self.grid[y] = row | self.grid[y];
+            self.grid[y] |= row;
         }
         let top = shape.location.1 + shape.height;
         if top > self.high_index {
@@ -210,20 +205,16 @@ fn get_shapes(right_border: usize) -> Vec<Shape> {
     result
 }
 
-fn read_input() -> Vec<Jet> {
-    let line = io::stdin().lines().next().unwrap();
-    match line {
-        Err(error) => {
-            panic!("{}", error);
+fn read_input(input: &str) -> ParseResult<Vec<Jet>> {
+    let line = input.lines().next().unwrap_or("");
+    let mut cursor = Cursor::new(1, line);
+    let mut jets = vec![];
+    loop {
+        let jet = cursor.one_of("\"<\" or \">\"", &[("<", Jet::Left), (">", Jet::Right)])?;
+        jets.push(jet);
+        if cursor.is_end() {
+            return Ok(jets);
         }
-        Ok(value) => value
-            .chars()
-            .map(|char| match char {
-                '<' => Jet::Left,
-                '>' => Jet::Right,
-                _ => panic!("Wrong char"),
-            })
-            .collect(),
     }
 }
 
@@ -279,19 +270,17 @@ fn part_two(grid: &Grid) -> u64 {
     grid.shift + grid.high_index as u64
 }
 
-fn main() {
+pub fn tower_height(input: &str) -> ParseResult<u64> {
     let rocks = 1_000_000_000_000;
 
-    let jets = read_input();
+    let jets = read_input(input)?;
     let grid = solve(jets, rocks);
-    let top = part_two(&grid);
-
-    println!("Result: {}", top);
+    Ok(part_two(&grid))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{get_shapes, part_two, solve, Grid, Jet, Shape};
+    use crate::two_slow::{get_shapes, part_two, solve, Grid, Jet, Shape};
 
     #[test]
     fn test_1() {
@@ -501,7 +490,7 @@ mod tests {
     fn get_shapes_height() {
         let heights: Vec<usize> = get_shapes(7).iter().map(|shape| shape.height).collect();
         assert_eq!(heights, vec![0, 2, 2, 3, 1]); // indices not actual height
-        // assert_eq!(heights, vec![1, 3, l, 4, 2]);
+                                                  // assert_eq!(heights, vec![1, 3, l, 4, 2]);
     }
 
     #[test]
@@ -515,7 +504,7 @@ mod tests {
 
     #[test]
     fn binary_ops() {
-        assert_eq!(0b1111000 >> 0, 0b1111000);
+        assert_eq!(0b1111000, 0b1111000);
         assert_eq!(0b0001111 << 2, 0b0111100);
         assert_eq!(0b0001111 >> 2, 0b0000011);
         assert_eq!(0b0001111 & 0b0011000, 0b0001000);
@@ -571,19 +560,19 @@ mod tests {
         shape.set_location(0, 9);
         assert_eq!(
             shape.iter_rows().collect::<Vec<(usize, u8)>>(),
-            vec![(09, 0b0100000), (10, 0b1110000), (11, 0b0100000),]
+            vec![(9, 0b0100000), (10, 0b1110000), (11, 0b0100000),]
         );
 
         shape.move_right();
         assert_eq!(
             shape.iter_rows().collect::<Vec<(usize, u8)>>(),
-            vec![(09, 0b0010000), (10, 0b0111000), (11, 0b0010000),]
+            vec![(9, 0b0010000), (10, 0b0111000), (11, 0b0010000),]
         );
 
         shape.move_right();
         assert_eq!(
             shape.iter_rows().collect::<Vec<(usize, u8)>>(),
-            vec![(09, 0b0001000), (10, 0b0011100), (11, 0b0001000),]
+            vec![(9, 0b0001000), (10, 0b0011100), (11, 0b0001000),]
         );
 
         shape.move_right();
@@ -591,7 +580,7 @@ mod tests {
         shape.move_right();
         assert_eq!(
             shape.iter_rows().collect::<Vec<(usize, u8)>>(),
-            vec![(09, 0b0000010), (10, 0b0000111), (11, 0b0000010),]
+            vec![(9, 0b0000010), (10, 0b0000111), (11, 0b0000010),]
         );
     }
 
@@ -720,19 +709,19 @@ mod tests {
             ],
         );
         shape.set_location(0, 0);
-        assert_eq!(grid.contains(&shape), true);
+        assert!(grid.contains(&shape));
 
         shape.set_location(2, 1);
-        assert_eq!(grid.contains(&shape), true);
+        assert!(grid.contains(&shape));
 
         shape.set_location(1, 2);
-        assert_eq!(grid.contains(&shape), true);
+        assert!(grid.contains(&shape));
 
         shape.set_location(1, 3);
-        assert_eq!(grid.contains(&shape), false);
+        assert!(!grid.contains(&shape));
 
         shape.set_location(3, 5);
-        assert_eq!(grid.contains(&shape), false);
+        assert!(!grid.contains(&shape));
 
         let mut shape = Shape::new(
             3,
@@ -744,10 +733,10 @@ mod tests {
             ],
         );
         shape.set_location(4, 5);
-        assert_eq!(grid.contains(&shape), false);
+        assert!(!grid.contains(&shape));
 
         shape.set_location(2, 7);
-        assert_eq!(grid.contains(&shape), true);
+        assert!(grid.contains(&shape));
     }
 
     #[test]
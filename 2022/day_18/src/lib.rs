@@ -1,11 +1,11 @@
+use aoc_common::{numbered_lines, Answer, Cursor, ParseError, ParseResult, Solution};
 use petgraph::{algo::kosaraju_scc, graph::NodeIndex, prelude::UnGraph};
 use std::{
+    any::Any,
     collections::HashSet,
     fmt::Debug,
     hash::Hash,
-    io,
     ops::{Add, Sub},
-    str::FromStr,
 };
 
 #[derive(Debug, PartialOrd, Ord, Hash, PartialEq, Eq, Copy, Clone)]
@@ -15,24 +15,16 @@ struct Voxel {
     z: i32,
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct ParseVoxelError;
-
-impl FromStr for Voxel {
-    type Err = ParseVoxelError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split(',').collect::<Vec<&str>>();
-        if parts.len() != 3 {
-            return Err(ParseVoxelError);
-        }
-
-        let x = parts[0].parse::<i32>().map_err(|_| ParseVoxelError)?;
-        let y = parts[1].parse::<i32>().map_err(|_| ParseVoxelError)?;
-        let z = parts[2].parse::<i32>().map_err(|_| ParseVoxelError)?;
-
-        Ok(Voxel::new(x, y, z))
-    }
+// A voxel is written as x,y,z
+fn parse_row(number: usize, line: &str) -> ParseResult<Voxel> {
+    let mut cursor = Cursor::new(number, line);
+    let x = cursor.number::<i32>()?;
+    cursor.tag(",")?;
+    let y = cursor.number::<i32>()?;
+    cursor.tag(",")?;
+    let z = cursor.number::<i32>()?;
+    cursor.end()?;
+    Ok(Voxel::new(x, y, z))
 }
 
 impl Add for Voxel {
@@ -91,7 +83,7 @@ struct System {
 }
 
 impl System {
-    fn new(voxels: &Vec<Voxel>) -> System {
+    fn new(voxels: &[Voxel]) -> System {
         let mut graph = UnGraph::<Voxel, ()>::default();
         let mut nodes = Vec::new();
         for v in voxels {
@@ -111,9 +103,9 @@ impl System {
                 }
                 seen.insert((i1, i2));
                 seen.insert((i2, i1));
-                if w1.is_close(&w2) {
-                    let n1 = nodes[i1].clone();
-                    let n2 = nodes[i2].clone();
+                if w1.is_close(w2) {
+                    let n1 = nodes[i1];
+                    let n2 = nodes[i2];
                     edges.push((n1, n2));
                 }
             }
@@ -133,19 +125,18 @@ impl System {
     }
 }
 
-fn read_input() -> Vec<Voxel> {
-    io::stdin()
-        .lines()
-        .map(|line| match line {
-            Err(error) => {
-                panic!("{}", error);
-            }
-            Ok(value) => value.as_str().parse::<Voxel>().unwrap(),
-        })
-        .collect()
+fn read_input(input: &str) -> ParseResult<Vec<Voxel>> {
+    let voxels: Vec<Voxel> = numbered_lines(input)
+        .map(|(number, line)| parse_row(number, line))
+        .collect::<ParseResult<_>>()?;
+    // the bounding box needs at least one voxel
+    if voxels.is_empty() {
+        return Err(ParseError::end_of_input(1, "a voxel"));
+    }
+    Ok(voxels)
 }
 
-fn get_bounding_box(items: &Vec<Voxel>) -> (i32, i32, i32, i32, i32, i32) {
+fn get_bounding_box(items: &[Voxel]) -> (i32, i32, i32, i32, i32, i32) {
     let min_x = items.iter().min_by_key(|v| v.x).unwrap().x;
     let max_x = items.iter().max_by_key(|v| v.x).unwrap().x;
     let min_y = items.iter().min_by_key(|v| v.y).unwrap().y;
@@ -168,7 +159,7 @@ fn extend_bounding_box(bb: (i32, i32, i32, i32, i32, i32)) -> (i32, i32, i32, i3
     )
 }
 
-fn invert_voxels(items: &Vec<Voxel>) -> Vec<Voxel> {
+fn invert_voxels(items: &[Voxel]) -> Vec<Voxel> {
     // extend bounding box by 1 in each side
     // so thats mean solid will be completely within air
     let (min_x, max_x, min_y, max_y, min_z, max_z) = extend_bounding_box(get_bounding_box(items));
@@ -186,7 +177,7 @@ fn invert_voxels(items: &Vec<Voxel>) -> Vec<Voxel> {
     others
 }
 
-fn part_one(items: &Vec<Voxel>) -> u32 {
+fn part_one(items: &[Voxel]) -> u32 {
     let sys = System::new(items);
     // 6 neighbors -> all sides of the voxel are adjacent with others
     // 0 neighbors -> voxel is completely separate
@@ -200,7 +191,7 @@ fn part_one(items: &Vec<Voxel>) -> u32 {
     sum
 }
 
-fn part_two(items: &Vec<Voxel>) -> u32 {
+fn part_two(items: &[Voxel]) -> u32 {
     let sys = System::new(items);
     // 6 neighbors -> all sides of the voxel are adjacent with others
     // 0 neighbors -> voxel is completely separate
@@ -236,14 +227,20 @@ fn part_two(items: &Vec<Voxel>) -> u32 {
     sum
 }
 
-fn main() {
-    let items = read_input();
+pub struct Day18;
 
-    let result = part_one(&items);
-    println!("Part one: {}", result);
+impl Solution for Day18 {
+    fn parse(&self, input: &str) -> Option<ParseResult<Box<dyn Any>>> {
+        Some(read_input(input).map(|x| Box::new(x) as Box<dyn Any>))
+    }
+
+    fn part_one(&self, input: &str) -> ParseResult<Answer> {
+        Ok(part_one(&read_input(input)?).into())
+    }
 
-    let result = part_two(&items);
-    println!("Part two: {}", result);
+    fn part_two(&self, input: &str) -> ParseResult<Answer> {
+        Ok(part_two(&read_input(input)?).into())
+    }
 }
 
 #[cfg(test)]
@@ -251,41 +248,51 @@ mod tests {
     use petgraph::algo::kosaraju_scc;
 
     use crate::{
-        get_bounding_box, invert_voxels, part_one, part_two, ParseVoxelError, System, Voxel,
+        get_bounding_box, invert_voxels, parse_row, part_one, part_two, read_input, System, Voxel,
     };
+    use aoc_common::ParseError;
 
-    fn get_volume(items: &Vec<Voxel>) -> i32 {
+    fn get_volume(items: &[Voxel]) -> i32 {
         let (min_x, max_x, min_y, max_y, min_z, max_z) = get_bounding_box(items);
         let s = 1;
         (max_x - min_x + s) * (max_y - min_y + s) * (max_z - min_z + s)
     }
 
+    #[test]
+    fn empty_input() {
+        let error = ParseError::end_of_input(1, "a voxel");
+        assert_eq!(read_input(""), Err(error));
+    }
+
     #[test]
     fn parse_voxel() {
-        let result = "1,1,1".parse::<Voxel>();
+        let result = parse_row(1, "1,1,1");
         assert_eq!(result, Ok(Voxel::new(1, 1, 1)));
 
-        let result = "-1,1,1".parse::<Voxel>();
+        let result = parse_row(1, "-1,1,1");
         assert_eq!(result, Ok(Voxel::new(-1, 1, 1)));
 
-        let result = "1-1,1".parse::<Voxel>();
-        assert_eq!(result, Err(ParseVoxelError));
+        let result = parse_row(3, "1-1,1");
+        assert_eq!(result, Err(ParseError::new(3, 2, "\",\"", "\"-1,1\"")));
 
-        let result = "".parse::<Voxel>();
-        assert_eq!(result, Err(ParseVoxelError));
+        let result = parse_row(1, "");
+        assert_eq!(
+            result,
+            Err(ParseError::new(1, 1, "a number", "end of line"))
+        );
     }
 
     #[test]
     fn voxel_is_close() {
         let a = Voxel::new(2, 2, 2);
         let b = Voxel::new(1, 2, 2);
-        assert_eq!(a.is_close(&b), true);
+        assert!(a.is_close(&b));
 
         let b = Voxel::new(3, 2, 2);
-        assert_eq!(a.is_close(&b), true);
+        assert!(a.is_close(&b));
 
         let b = Voxel::new(2, 2, 4);
-        assert_eq!(a.is_close(&b), false);
+        assert!(!a.is_close(&b));
     }
 
     #[test]
@@ -333,17 +340,18 @@ mod tests {
     fn invert_voxels_simple() {
         let items = vec![Voxel::new(0, 0, 0), Voxel::new(1, 1, 1)];
         let inverted = invert_voxels(&items);
-        assert_eq!(
-            inverted,
-            vec![
-                Voxel::new(0, 0, 1),
-                Voxel::new(0, 1, 0),
-                Voxel::new(0, 1, 1),
-                Voxel::new(1, 0, 0),
-                Voxel::new(1, 0, 1),
-                Voxel::new(1, 1, 0),
-            ]
-        );
+
+        // bounding box is extended by 1 in each side: 4x4x4
+        assert_eq!(inverted.len(), 4 * 4 * 4 - items.len());
+        let inner = [
+            Voxel::new(0, 0, 1),
+            Voxel::new(0, 1, 0),
+            Voxel::new(0, 1, 1),
+            Voxel::new(1, 0, 0),
+            Voxel::new(1, 0, 1),
+            Voxel::new(1, 1, 0),
+        ];
+        assert!(inner.iter().all(|v| inverted.contains(v)));
     }
 
     #[test]
@@ -365,7 +373,7 @@ mod tests {
         ];
         let inverted = invert_voxels(&items);
         let all = items.iter().all(|v| !inverted.contains(v));
-        assert_eq!(all, true);
+        assert!(all);
     }
 
     #[test]
@@ -387,8 +395,11 @@ mod tests {
         ];
         let volume = get_volume(&items) as usize;
         assert_eq!(volume, 54);
+
+        // bounding box is extended by 1 in each side: 3x3x6 -> 5x5x8
+        let extended_volume = 5 * 5 * 8;
         let inverted = invert_voxels(&items);
-        assert_eq!(inverted.len(), volume - items.len());
+        assert_eq!(inverted.len(), extended_volume - items.len());
     }
 
     #[test]
@@ -414,7 +425,7 @@ mod tests {
 
         assert_eq!(islands.len(), 2);
         assert_eq!(islands[0].len(), 1);
-        assert_eq!(islands[1].len(), 40);
+        assert_eq!(islands[1].len(), 186);
     }
 
     #[test]
@@ -0,0 +1,493 @@
+use aoc_common::{numbered_lines, search, Answer, Cursor, Mode, ParseResult, Problem, Solution};
+use std::{any::Any, fmt::Debug};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct State {
+    time: u16,
+    ore: u16,
+    clay: u16,
+    obsidian: u16,
+    geode: u16,
+    ore_robots: u16,
+    clay_robots: u16,
+    obsidian_robots: u16,
+    geode_robots: u16,
+}
+
+impl State {
+    // one ore robot and nothing else at the very beginning
+    fn start(time: u16) -> State {
+        State {
+            time,
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geode: 0,
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+            geode_robots: 0,
+        }
+    }
+
+    fn has_time(&self) -> bool {
+        self.time > 0
+    }
+
+    fn enough_robots(&self, cost: (u16, u16, u16, u16)) -> bool {
+        let (ore, clay, obsidian, geode) = cost;
+        self.ore_robots > ore
+            && self.clay_robots > clay
+            && self.obsidian_robots > obsidian
+            && self.geode_robots > geode
+    }
+
+    fn enough_resources(&self, cost: (u16, u16, u16, u16)) -> bool {
+        let (ore, clay, obsidian, geode) = cost;
+        self.ore >= ore && self.clay >= clay && self.obsidian >= obsidian && self.geode >= geode
+    }
+
+    fn create_robot(&mut self, robot: (u16, u16, u16, u16), cost: (u16, u16, u16, u16)) {
+        // + robots
+        self.ore_robots += robot.0;
+        self.clay_robots += robot.1;
+        self.obsidian_robots += robot.2;
+        self.geode_robots += robot.3;
+
+        // - resources
+        self.ore -= cost.0;
+        self.clay -= cost.1;
+        self.obsidian -= cost.2;
+        self.geode -= cost.3;
+    }
+
+    fn tick(&mut self) {
+        if self.time > 0 {
+            self.ore += self.ore_robots;
+            self.clay += self.clay_robots;
+            self.obsidian += self.obsidian_robots;
+            self.geode += self.geode_robots;
+            self.time -= 1;
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Blueprint {
+    id: u16,
+
+    ore_robot_cost: (u16, u16, u16, u16),
+    clay_robot_cost: (u16, u16, u16, u16),
+    obsidian_robot_cost: (u16, u16, u16, u16),
+    geode_robot_cost: (u16, u16, u16, u16),
+}
+
+fn parse_blueprint(number: usize, line: &str) -> ParseResult<Blueprint> {
+    let mut cursor = Cursor::new(number, line);
+    cursor.tag("Blueprint ")?;
+    let id = cursor.number::<u16>()?;
+    cursor.tag(": Each ore robot costs ")?;
+    let ore_cost = cursor.number::<u16>()?;
+    cursor.tag(" ore. Each clay robot costs ")?;
+    let clay_cost = cursor.number::<u16>()?;
+    cursor.tag(" ore. Each obsidian robot costs ")?;
+    let obsidian_cost_ore = cursor.number::<u16>()?;
+    cursor.tag(" ore and ")?;
+    let obsidian_cost_clay = cursor.number::<u16>()?;
+    cursor.tag(" clay. Each geode robot costs ")?;
+    let geode_cost_ore = cursor.number::<u16>()?;
+    cursor.tag(" ore and ")?;
+    let geode_cost_obsidian = cursor.number::<u16>()?;
+    cursor.tag(" obsidian.")?;
+    cursor.end()?;
+
+    Ok(Blueprint {
+        id,
+        ore_robot_cost: (ore_cost, 0, 0, 0),
+        clay_robot_cost: (clay_cost, 0, 0, 0),
+        obsidian_robot_cost: (obsidian_cost_ore, obsidian_cost_clay, 0, 0),
+        geode_robot_cost: (geode_cost_ore, 0, geode_cost_obsidian, 0),
+    })
+}
+
+impl Blueprint {
+    fn evaluate(&self, state: State) -> u16 {
+        search(self, state, Mode::BranchAndBound).score
+    }
+}
+
+impl Problem for Blueprint {
+    type State = State;
+    type Key = State;
+    type Score = u16;
+
+    fn successors(&self, state: &State, next: &mut Vec<State>) {
+        // state is wasted
+        if !state.has_time() {
+            return;
+        }
+
+        // check unique branch where we buy geode robot
+        if state.enough_resources(self.geode_robot_cost) {
+            let mut next_state = *state;
+            next_state.tick();
+            next_state.create_robot((0, 0, 0, 1), self.geode_robot_cost);
+            next.push(next_state);
+
+            // no need to check brances where other robots can be build at this step
+            // nor earning resources
+            return;
+        }
+
+        // check branch where we buy obsidian robot
+        if state.enough_resources(self.obsidian_robot_cost) {
+            let mut next_state = *state;
+            next_state.tick();
+            next_state.create_robot((0, 0, 1, 0), self.obsidian_robot_cost);
+            next.push(next_state);
+        }
+
+        // check branch where we buy clay robot
+        if state.enough_resources(self.clay_robot_cost)
+            && !state.enough_robots(self.clay_robot_cost)
+        {
+            let mut next_state = *state;
+            next_state.tick();
+            next_state.create_robot((0, 1, 0, 0), self.clay_robot_cost);
+            next.push(next_state);
+        }
+
+        // check branch where we buy ore robot
+        if state.enough_resources(self.ore_robot_cost) && !state.enough_robots(self.ore_robot_cost)
+        {
+            let mut next_state = *state;
+            next_state.tick();
+            next_state.create_robot((1, 0, 0, 0), self.ore_robot_cost);
+            next.push(next_state);
+        }
+
+        let mut can_buy = 0;
+        if state.enough_resources(self.ore_robot_cost) {
+            can_buy += 1;
+        }
+        if state.enough_resources(self.clay_robot_cost) {
+            can_buy += 1;
+        }
+        if state.enough_resources(self.obsidian_robot_cost) {
+            can_buy += 1;
+        }
+
+        // add current state too
+        // as an option if strategy is to accumulate resources
+        // do not accumulate resources if can build some robots
+        if state.time > 2 && can_buy < 2 {
+            let mut no_robot_state = *state;
+            no_robot_state.tick();
+            next.push(no_robot_state);
+        }
+    }
+
+    // geodes collected when the robots keep working until the end
+    fn score(&self, state: &State) -> u16 {
+        state.geode + state.geode_robots * state.time
+    }
+
+    // as if a new geode robot was built every remaining minute
+    fn upper_bound(&self, state: &State) -> u16 {
+        self.score(state) + state.time * state.time.saturating_sub(1) / 2
+    }
+
+    fn key(&self, state: &State) -> State {
+        *state
+    }
+}
+
+fn read_input(input: &str) -> ParseResult<Vec<Blueprint>> {
+    numbered_lines(input)
+        .map(|(number, line)| parse_blueprint(number, line))
+        .collect()
+}
+
+fn part_one(blueprints: &[Blueprint], state: State) -> u16 {
+    blueprints
+        .iter()
+        .map(|blueprint| {
+            let geodes_earned = blueprint.evaluate(state);
+            blueprint.id * geodes_earned
+        })
+        .sum()
+}
+
+fn part_two(blueprints: &[Blueprint], state: State) -> u16 {
+    blueprints
+        .iter()
+        .take(3)
+        .map(|blueprint| blueprint.evaluate(state))
+        .product()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn parse(&self, input: &str) -> Option<ParseResult<Box<dyn Any>>> {
+        Some(read_input(input).map(|x| Box::new(x) as Box<dyn Any>))
+    }
+
+    fn part_one(&self, input: &str) -> ParseResult<Answer> {
+        Ok(part_one(&read_input(input)?, State::start(24)).into())
+    }
+
+    fn part_two(&self, input: &str) -> ParseResult<Answer> {
+        Ok(part_two(&read_input(input)?, State::start(32)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::ParseError;
+
+    use crate::{parse_blueprint, Blueprint, State};
+
+    #[test]
+    fn blueprint_from_str() {
+        let blueprint = parse_blueprint(1, "Blueprint 18: Each ore robot costs 3 ore. Each clay robot costs 4 ore. Each obsidian robot costs 4 ore and 18 clay. Each geode robot costs 4 ore and 12 obsidian.").unwrap();
+        assert_eq!(
+            blueprint,
+            Blueprint {
+                id: 18,
+                ore_robot_cost: (3, 0, 0, 0),
+                clay_robot_cost: (4, 0, 0, 0),
+                obsidian_robot_cost: (4, 18, 0, 0),
+                geode_robot_cost: (4, 0, 12, 0),
+            }
+        );
+    }
+
+    #[test]
+    fn blueprint_reports_column() {
+        let result = parse_blueprint(2, "Blueprint 2: Each ore robot costs x ore.");
+        assert_eq!(result, Err(ParseError::new(2, 35, "a number", "\"x\"")));
+    }
+
+    #[test]
+    fn blueprint1_9geode() {
+        let blueprint = Blueprint {
+            id: 1,
+            ore_robot_cost: (4, 0, 0, 0),
+            clay_robot_cost: (2, 0, 0, 0),
+            obsidian_robot_cost: (3, 14, 0, 0),
+            geode_robot_cost: (2, 0, 7, 0),
+        };
+        let result = blueprint.evaluate(State {
+            time: 24,
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geode: 0,
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+            geode_robots: 0,
+        });
+        assert_eq!(result, 9);
+    }
+
+    #[test]
+    fn blueprint1_56geode() {
+        let blueprint = Blueprint {
+            id: 1,
+            ore_robot_cost: (4, 0, 0, 0),
+            clay_robot_cost: (2, 0, 0, 0),
+            obsidian_robot_cost: (3, 14, 0, 0),
+            geode_robot_cost: (2, 0, 7, 0),
+        };
+        let result = blueprint.evaluate(State {
+            time: 32,
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geode: 0,
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+            geode_robots: 0,
+        });
+        assert_eq!(result, 56);
+    }
+
+    #[test]
+    fn blueprint2_12geode() {
+        let blueprint = Blueprint {
+            id: 2,
+            ore_robot_cost: (2, 0, 0, 0),
+            clay_robot_cost: (3, 0, 0, 0),
+            obsidian_robot_cost: (3, 8, 0, 0),
+            geode_robot_cost: (3, 0, 12, 0),
+        };
+        let result = blueprint.evaluate(State {
+            time: 24,
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geode: 0,
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+            geode_robots: 0,
+        });
+        assert_eq!(result, 12);
+    }
+
+    #[test]
+    fn blueprint2_62geode() {
+        let blueprint = Blueprint {
+            id: 2,
+            ore_robot_cost: (2, 0, 0, 0),
+            clay_robot_cost: (3, 0, 0, 0),
+            obsidian_robot_cost: (3, 8, 0, 0),
+            geode_robot_cost: (3, 0, 12, 0),
+        };
+        let result = blueprint.evaluate(State {
+            time: 32,
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geode: 0,
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+            geode_robots: 0,
+        });
+        assert_eq!(result, 62);
+    }
+
+    #[test]
+    fn state_has_time() {
+        let mut state = State {
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geode: 0,
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+            geode_robots: 0,
+            time: 24,
+        };
+        assert!(state.has_time());
+        let mut trues = [false; 24];
+        for (i, _) in (0..24).enumerate() {
+            trues[i] = state.has_time();
+            state.tick();
+        }
+        assert!(trues.iter().all(|x| *x));
+        assert!(!state.has_time());
+    }
+
+    #[test]
+    fn state_create_robot() {
+        let mut state = State {
+            ore: 7,
+            clay: 8,
+            obsidian: 14,
+            geode: 0,
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+            geode_robots: 0,
+            time: 11,
+        };
+        state.create_robot((0, 0, 0, 1), (2, 0, 7, 0));
+        assert_eq!(
+            state,
+            State {
+                ore: 5,
+                clay: 8,
+                obsidian: 7,
+                geode: 0,
+                ore_robots: 1,
+                clay_robots: 0,
+                obsidian_robots: 0,
+                geode_robots: 1,
+                time: 11,
+            }
+        );
+        state.create_robot((0, 0, 1, 0), (3, 4, 0, 0));
+        assert_eq!(
+            state,
+            State {
+                ore: 2,
+                clay: 4,
+                obsidian: 7,
+                geode: 0,
+                ore_robots: 1,
+                clay_robots: 0,
+                obsidian_robots: 1,
+                geode_robots: 1,
+                time: 11,
+            }
+        );
+    }
+
+    #[test]
+    fn state_enough_resources() {
+        let state = State {
+            ore: 7,
+            clay: 8,
+            obsidian: 14,
+            geode: 0,
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+            geode_robots: 0,
+            time: 11,
+        };
+        assert!(state.enough_resources((2, 0, 7, 0)));
+        assert!(!state.enough_resources((2, 9, 7, 0)));
+    }
+
+    #[test]
+    fn state_tick() {
+        let mut state = State {
+            ore: 7,
+            clay: 8,
+            obsidian: 14,
+            geode: 0,
+            ore_robots: 2,
+            clay_robots: 4,
+            obsidian_robots: 3,
+            geode_robots: 1,
+            time: 10,
+        };
+        state.tick();
+        assert_eq!(
+            state,
+            State {
+                ore: 9,
+                clay: 12,
+                obsidian: 17,
+                geode: 1,
+                ore_robots: 2,
+                clay_robots: 4,
+                obsidian_robots: 3,
+                geode_robots: 1,
+                time: 9,
+            }
+        );
+        state.tick();
+        assert_eq!(
+            state,
+            State {
+                ore: 11,
+                clay: 16,
+                obsidian: 20,
+                geode: 2,
+                ore_robots: 2,
+                clay_robots: 4,
+                obsidian_robots: 3,
+                geode_robots: 1,
+                time: 8,
+            }
+        );
+    }
+}
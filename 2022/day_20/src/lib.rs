@@ -0,0 +1,96 @@
+use aoc_common::{numbered_lines, Answer, Cursor, ParseError, ParseResult, Solution};
+use mixer::{History, Mixer};
+use std::any::Any;
+
+pub mod mixer;
+
+fn read_input(input: &str) -> ParseResult<Vec<i64>> {
+    numbered_lines(input)
+        .map(|(number, line)| Cursor::new(number, line).value::<i64>("a number"))
+        .collect()
+}
+
+// None when there is no 0 to count from
+fn grove_coordinates(history: &History) -> Option<i64> {
+    [1000, 2000, 3000]
+        .iter()
+        .map(|offset| history.after(0, *offset, history.rounds()))
+        .sum()
+}
+
+fn no_zero(input: &str) -> ParseError {
+    ParseError::end_of_input(input.lines().count() + 1, "a number 0")
+}
+
+fn part_one(input: &[i64]) -> Option<i64> {
+    grove_coordinates(&Mixer::history(input.to_vec(), 1))
+}
+
+fn part_two(input: &[i64]) -> Option<i64> {
+    let decryption_key = 811589153;
+    let values = input.iter().map(|x| x * decryption_key).collect();
+    grove_coordinates(&Mixer::history(values, 10))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    fn parse(&self, input: &str) -> Option<ParseResult<Box<dyn Any>>> {
+        Some(read_input(input).map(|x| Box::new(x) as Box<dyn Any>))
+    }
+
+    fn part_one(&self, input: &str) -> ParseResult<Answer> {
+        let answer = part_one(&read_input(input)?).ok_or_else(|| no_zero(input))?;
+        Ok(answer.into())
+    }
+
+    fn part_two(&self, input: &str) -> ParseResult<Answer> {
+        let answer = part_two(&read_input(input)?).ok_or_else(|| no_zero(input))?;
+        Ok(answer.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{grove_coordinates, mixer::Mixer, part_one, part_two, read_input, Day20};
+    use aoc_common::{ParseError, Solution};
+
+    #[test]
+    fn example_parts() {
+        let input = [1, 2, -3, 3, -2, 0, 4];
+        assert_eq!(part_one(&input), Some(3));
+        assert_eq!(part_two(&input), Some(1623178306));
+    }
+
+    #[test]
+    fn coordinates_need_a_zero() {
+        assert_eq!(part_one(&[1, 2, -3]), None);
+        let error = ParseError::end_of_input(4, "a number 0");
+        assert_eq!(Day20.part_two("1\n2\n-3"), Err(error));
+        let error = ParseError::end_of_input(1, "a number 0");
+        assert_eq!(Day20.part_one(""), Err(error));
+    }
+
+    // The history of the test fixture agrees with both parts and with
+    // a round by round mixer, and unmixing it gives back the input
+    #[test]
+    fn history_of_the_test_file() {
+        let input = read_input(include_str!("../test.txt")).unwrap();
+        assert_eq!(part_one(&input), Some(3));
+        assert_eq!(part_two(&input), Some(1623178306));
+
+        let values: Vec<i64> = input.iter().map(|x| x * 811589153).collect();
+        let history = Mixer::history(values.clone(), 10);
+        assert_eq!(grove_coordinates(&history), part_two(&input));
+
+        let mut mixer = Mixer::new(values.clone());
+        for round in 1..=10 {
+            mixer.mix();
+            assert_eq!(mixer.order(), history.order(round), "round {}", round);
+        }
+        for _ in 0..10 {
+            mixer.unmix();
+        }
+        assert_eq!(mixer.order_from(0), history.order(0));
+    }
+}
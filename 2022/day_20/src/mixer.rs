@@ -0,0 +1,283 @@
+// Circular list of the numbers split into blocks of about sqrt(n) elements,
+// an element is found, removed and inserted by walking the blocks instead of
+// shifting the whole list. The elements are known by their original index
+#[derive(Debug, Clone)]
+pub struct Mixer {
+    values: Vec<i64>,
+    blocks: Vec<Vec<usize>>,
+    block_of: Vec<usize>,
+    block_size: usize,
+    // moves since the blocks were last balanced
+    moves: usize,
+}
+
+impl Mixer {
+    pub fn new(values: Vec<i64>) -> Mixer {
+        let block_size = (values.len() as f64).sqrt().ceil().max(1.0) as usize;
+        let mut mixer = Mixer {
+            block_of: vec![0; values.len()],
+            blocks: vec![(0..values.len()).collect()],
+            values,
+            block_size,
+            moves: 0,
+        };
+        mixer.rebuild();
+        mixer
+    }
+
+    // A list that is already mixed, the order holds original indices
+    pub fn from_order(values: Vec<i64>, order: &[usize]) -> Mixer {
+        let mut mixer = Mixer::new(values);
+        mixer.blocks = vec![order.to_vec()];
+        mixer.rebuild();
+        mixer
+    }
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn value(&self, id: usize) -> i64 {
+        self.values[id]
+    }
+
+    // Splits the current order into blocks of the same size again
+    fn rebuild(&mut self) {
+        let order = self.order();
+        self.blocks = order
+            .chunks(self.block_size)
+            .map(|chunk| chunk.to_vec())
+            .collect();
+        for (b, block) in self.blocks.iter().enumerate() {
+            for id in block {
+                self.block_of[*id] = b;
+            }
+        }
+        self.moves = 0;
+    }
+
+    // Original indices in the current order
+    pub fn order(&self) -> Vec<usize> {
+        self.blocks.iter().flatten().copied().collect()
+    }
+
+    // The circle read from an original element on
+    pub fn order_from(&self, id: usize) -> Vec<usize> {
+        let start = self.position(id);
+        (start..start + self.len())
+            .map(|index| self.at(index))
+            .collect()
+    }
+
+    // Current index of an original element
+    pub fn position(&self, id: usize) -> usize {
+        let b = self.block_of[id];
+        let before: usize = self.blocks[..b].iter().map(|block| block.len()).sum();
+        before + self.blocks[b].iter().position(|x| *x == id).unwrap()
+    }
+
+    // Block and offset inside of it of a current index
+    fn locate(&self, index: usize) -> (usize, usize) {
+        let mut index = index;
+        for (b, block) in self.blocks.iter().enumerate() {
+            if index < block.len() {
+                return (b, index);
+            }
+            index -= block.len();
+        }
+        // one past the end goes to the end of the last block
+        (self.blocks.len() - 1, self.blocks.last().unwrap().len())
+    }
+
+    // Original element at a current index
+    pub fn at(&self, index: usize) -> usize {
+        let (b, offset) = self.locate(index % self.len());
+        self.blocks[b][offset]
+    }
+
+    // Moves an element by steps in a circle of the other elements
+    pub fn move_by(&mut self, id: usize, steps: i64) {
+        if self.len() < 2 {
+            return;
+        }
+        let others = self.len() as i64 - 1;
+        let index = self.position(id);
+        let b = self.block_of[id];
+        self.blocks[b].retain(|x| *x != id);
+
+        let target = (index as i64 + steps).rem_euclid(others) as usize;
+        let (b, offset) = self.locate(target);
+        self.blocks[b].insert(offset, id);
+        self.block_of[id] = b;
+
+        self.moves += 1;
+        if self.moves >= self.block_size {
+            self.rebuild();
+        }
+    }
+
+    // Moves every element by its value in the original order
+    pub fn mix(&mut self) {
+        for id in 0..self.len() {
+            self.move_by(id, self.values[id]);
+        }
+    }
+
+    // Undoes a round of mixing, every element moves back in the reverse order.
+    // The circle is the one before the round, it may start somewhere else
+    pub fn unmix(&mut self) {
+        for id in (0..self.len()).rev() {
+            self.move_by(id, -self.values[id]);
+        }
+    }
+
+    // Mixes a number of rounds and keeps the order after every one of them
+    pub fn history(values: Vec<i64>, rounds: usize) -> History {
+        let mut mixer = Mixer::new(values);
+        let mut orders = vec![mixer.order()];
+        for _ in 0..rounds {
+            mixer.mix();
+            orders.push(mixer.order());
+        }
+        History::new(mixer.values, orders)
+    }
+
+    // The value some places after the first element with a value, like the
+    // grove coordinates 1000, 2000 and 3000 after 0
+    pub fn after(&self, value: i64, offset: usize) -> Option<i64> {
+        let id = self.values.iter().position(|x| *x == value)?;
+        Some(self.value(self.at(self.position(id) + offset)))
+    }
+}
+
+// The order of the elements after every round, round 0 is the input
+#[derive(Debug, Clone)]
+pub struct History {
+    values: Vec<i64>,
+    orders: Vec<Vec<usize>>,
+    // positions[round][id] is the index of an original element
+    positions: Vec<Vec<usize>>,
+}
+
+impl History {
+    fn new(values: Vec<i64>, orders: Vec<Vec<usize>>) -> History {
+        let positions = orders
+            .iter()
+            .map(|order| {
+                let mut positions = vec![0; order.len()];
+                for (index, id) in order.iter().enumerate() {
+                    positions[*id] = index;
+                }
+                positions
+            })
+            .collect();
+        History {
+            values,
+            orders,
+            positions,
+        }
+    }
+
+    pub fn rounds(&self) -> usize {
+        self.orders.len() - 1
+    }
+
+    pub fn order(&self, round: usize) -> &[usize] {
+        &self.orders[round]
+    }
+
+    pub fn values(&self, round: usize) -> Vec<i64> {
+        self.orders[round]
+            .iter()
+            .map(|id| self.values[*id])
+            .collect()
+    }
+
+    // Where an original element is after a round
+    pub fn position(&self, id: usize, round: usize) -> usize {
+        self.positions[round][id]
+    }
+
+    // The value some places after the first element with a value after a round
+    pub fn after(&self, value: i64, offset: usize, round: usize) -> Option<i64> {
+        let id = self.values.iter().position(|x| *x == value)?;
+        let order = &self.orders[round];
+        let index = (self.position(id, round) + offset) % order.len();
+        Some(self.values[order[index]])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mixer::Mixer;
+
+    // The plain list mixing, every move shifts the list
+    fn mix_slowly(xs: &mut Vec<(usize, i64)>, order: &[(usize, i64)]) {
+        for x in order {
+            let i = xs.iter().position(|y| x == y).unwrap();
+            let im = (i as i64 + x.1).rem_euclid((xs.len() - 1) as i64) as usize;
+            xs.remove(i);
+            xs.insert(im, *x);
+        }
+    }
+
+    #[test]
+    fn mix_example() {
+        let mut mixer = Mixer::new(vec![1, 2, -3, 3, -2, 0, 4]);
+        mixer.mix();
+        let values: Vec<i64> = mixer.order().iter().map(|id| mixer.value(*id)).collect();
+        // the circle 1, 2, -3, 4, 0, 3, -2 from the puzzle
+        assert_eq!(values, vec![-2, 1, 2, -3, 4, 0, 3]);
+        assert_eq!(mixer.after(0, 1000), Some(4));
+        assert_eq!(mixer.after(0, 2000), Some(-3));
+        assert_eq!(mixer.after(0, 3000), Some(2));
+        assert_eq!(mixer.after(7, 1), None);
+    }
+
+    #[test]
+    fn same_order_as_the_plain_list() {
+        // a pseudo random list with repeated values and long moves
+        let values: Vec<i64> = (0..500_i64)
+            .map(|i| (i * 7919 % 1009 - 504) * if i % 3 == 0 { 97 } else { 1 })
+            .collect();
+        let xs: Vec<(usize, i64)> = values.iter().copied().enumerate().collect();
+        let mut plain = xs.clone();
+        let mut mixer = Mixer::new(values);
+        for _ in 0..3 {
+            mix_slowly(&mut plain, &xs);
+            mixer.mix();
+        }
+        let order: Vec<usize> = plain.iter().map(|(id, _)| *id).collect();
+        assert_eq!(mixer.order(), order);
+        for (index, id) in order.iter().enumerate() {
+            assert_eq!(mixer.position(*id), index);
+            assert_eq!(mixer.at(index), *id);
+        }
+    }
+
+    #[test]
+    fn history_of_the_example() {
+        let history = Mixer::history(vec![1, 2, -3, 3, -2, 0, 4], 2);
+        assert_eq!(history.rounds(), 2);
+        assert_eq!(history.values(0), vec![1, 2, -3, 3, -2, 0, 4]);
+        assert_eq!(history.values(1), vec![-2, 1, 2, -3, 4, 0, 3]);
+        // the 3 is at the end after the first round
+        assert_eq!(history.position(3, 1), 6);
+        assert_eq!(history.after(0, 1000, 1), Some(4));
+        assert_eq!(history.after(0, 1, 0), Some(4));
+        assert_eq!(history.order(0), &[0, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn unmix_recovers_every_round() {
+        let values: Vec<i64> = (0..200_i64).map(|i| (i * 7919 % 401 - 200) * 811).collect();
+        let history = Mixer::history(values.clone(), 4);
+        let mut mixer = Mixer::from_order(values, history.order(4));
+        for round in (0..4).rev() {
+            mixer.unmix();
+            let order = history.order(round);
+            assert_eq!(mixer.order_from(order[0]), order, "round {}", round);
+        }
+        assert_eq!(mixer.order_from(0), (0..200).collect::<Vec<usize>>());
+    }
+}
@@ -0,0 +1,361 @@
+use crate::{
+    rational::{gcd, Rational},
+    Job, Monkey, Op,
+};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExprError {
+    DivisionByZero,
+    // a monkey that was left out of the numbers
+    Unknown(String),
+    // the unknown is on both sides of an operation
+    NotLinear,
+    // the unknown is multiplied by zero, so every number or none solves it
+    NoUniqueSolution,
+    NoUnknown,
+    Overflow,
+    NotAnInteger(Rational),
+}
+
+impl Display for ExprError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExprError::DivisionByZero => write!(f, "division by zero"),
+            ExprError::Unknown(name) => write!(f, "\"{}\" has no number", name),
+            ExprError::NotLinear => write!(f, "the unknown is on both sides"),
+            ExprError::NoUniqueSolution => {
+                write!(f, "the unknown times zero has no unique solution")
+            }
+            ExprError::NoUnknown => write!(f, "nothing depends on the unknown"),
+            ExprError::Overflow => write!(f, "a number doesn't fit in 128 bits"),
+            ExprError::NotAnInteger(value) => write!(f, "{} is not a whole number", value),
+        }
+    }
+}
+
+impl Error for ExprError {}
+
+pub fn apply(op: Op, a: Rational, b: Rational) -> Result<Rational, ExprError> {
+    let value = match op {
+        Op::Add => a.checked_add(b),
+        Op::Sub => a.checked_sub(b),
+        Op::Mul => a.checked_mul(b),
+        Op::Div if b.is_zero() => return Err(ExprError::DivisionByZero),
+        Op::Div => a.checked_div(b),
+    };
+    value.ok_or(ExprError::Overflow)
+}
+
+// The tree of jobs below a monkey, everything that doesn't depend on
+// an unknown is folded into a number while it is built
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Number(Rational),
+    Unknown(String),
+    Operation(Op, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    pub fn build(
+        monkeys: &HashMap<&str, &Monkey>,
+        name: &str,
+        unknown: Option<&str>,
+    ) -> Result<Expr, ExprError> {
+        if Some(name) == unknown {
+            return Ok(Expr::Unknown(name.to_string()));
+        }
+        Ok(match &monkeys[name].job {
+            Job::Number(n) => Expr::Number(Rational::from(*n)),
+            Job::Operation((op, a, b)) => {
+                let a = Expr::build(monkeys, a, unknown)?;
+                let b = Expr::build(monkeys, b, unknown)?;
+                match (&a, &b) {
+                    // a division by zero stays around to fail in evaluate
+                    (Expr::Number(x), Expr::Number(y)) if !(*op == Op::Div && y.is_zero()) => {
+                        Expr::Number(apply(*op, *x, *y)?)
+                    }
+                    _ => Expr::Operation(*op, Box::new(a), Box::new(b)),
+                }
+            }
+        })
+    }
+
+    pub fn contains_unknown(&self) -> bool {
+        self.unknown().is_some()
+    }
+
+    pub fn evaluate(&self) -> Result<Rational, ExprError> {
+        match self {
+            Expr::Number(n) => Ok(*n),
+            Expr::Unknown(name) => Err(ExprError::Unknown(name.clone())),
+            Expr::Operation(op, a, b) => apply(*op, a.evaluate()?, b.evaluate()?),
+        }
+    }
+
+    // a * x + b for an expression that is linear in the unknown x
+    fn linear(&self) -> Result<(Rational, Rational), ExprError> {
+        let zero = Rational::from(0);
+        match self {
+            Expr::Number(n) => Ok((zero, *n)),
+            Expr::Unknown(_) => Ok((Rational::from(1), zero)),
+            Expr::Operation(op, a, b) => {
+                let ((a1, b1), (a2, b2)) = (a.linear()?, b.linear()?);
+                match op {
+                    Op::Add | Op::Sub => Ok((apply(*op, a1, a2)?, apply(*op, b1, b2)?)),
+                    Op::Mul if a1.is_zero() => Ok((apply(*op, b1, a2)?, apply(*op, b1, b2)?)),
+                    Op::Mul if a2.is_zero() => Ok((apply(*op, a1, b2)?, apply(*op, b1, b2)?)),
+                    Op::Div if a2.is_zero() && !b2.is_zero() => {
+                        Ok((apply(*op, a1, b2)?, apply(*op, b1, b2)?))
+                    }
+                    Op::Mul | Op::Div => Err(ExprError::NotLinear),
+                }
+            }
+        }
+    }
+
+    // Collects a linear expression into (a*x + b)/d with whole a, b and d,
+    // anything else stays as it is
+    pub fn simplify(&self) -> Expr {
+        let name = match self.unknown() {
+            Some(name) => name.to_string(),
+            None => return self.clone(),
+        };
+        let Some((a, b, d)) = self.linear().ok().and_then(|(a, b)| whole_terms(a, b)) else {
+            return self.clone();
+        };
+        let number = |n: i128| Box::new(Expr::Number(Rational::new(n, 1)));
+
+        // b - |a| x reads better than a negative factor
+        let (a, flip) = if a < 0 && b > 0 {
+            (-a, true)
+        } else {
+            (a, false)
+        };
+        let x = Box::new(Expr::Unknown(name));
+        let ax = match a {
+            0 => None,
+            1 => Some(x),
+            _ => Some(Box::new(Expr::Operation(Op::Mul, number(a), x))),
+        };
+        let sum = match ax {
+            None => number(b),
+            Some(ax) if flip => Box::new(Expr::Operation(Op::Sub, number(b), ax)),
+            Some(ax) if b == 0 => ax,
+            Some(ax) if b > 0 => Box::new(Expr::Operation(Op::Add, ax, number(b))),
+            Some(ax) => Box::new(Expr::Operation(Op::Sub, ax, number(-b))),
+        };
+        match d {
+            1 => *sum,
+            _ => Expr::Operation(Op::Div, sum, number(d)),
+        }
+    }
+
+    fn unknown(&self) -> Option<&str> {
+        match self {
+            Expr::Number(_) => None,
+            Expr::Unknown(name) => Some(name),
+            Expr::Operation(_, a, b) => a.unknown().or_else(|| b.unknown()),
+        }
+    }
+
+    // The unknown for which this expression has the target value. Every
+    // operation on the way down is undone on the target, the other side
+    // of an operation is a number as long as the unknown appears once
+    fn invert(&self, target: Rational) -> Result<Rational, ExprError> {
+        let (op, a, b) = match self {
+            Expr::Number(_) => return Err(ExprError::NoUnknown),
+            Expr::Unknown(_) => return Ok(target),
+            Expr::Operation(op, a, b) => (*op, a, b),
+        };
+        match (a.contains_unknown(), b.contains_unknown()) {
+            (true, true) => Err(ExprError::NotLinear),
+            (false, false) => Err(ExprError::NoUnknown),
+            // target = x op k
+            (true, false) => {
+                let k = b.evaluate()?;
+                let x = match op {
+                    Op::Add => apply(Op::Sub, target, k)?,
+                    Op::Sub => apply(Op::Add, target, k)?,
+                    Op::Mul if k.is_zero() => return Err(ExprError::NoUniqueSolution),
+                    Op::Mul => apply(Op::Div, target, k)?,
+                    Op::Div if k.is_zero() => return Err(ExprError::DivisionByZero),
+                    Op::Div => apply(Op::Mul, target, k)?,
+                };
+                a.invert(x)
+            }
+            // target = k op x
+            (false, true) => {
+                let k = a.evaluate()?;
+                let x = match op {
+                    Op::Add => apply(Op::Sub, target, k)?,
+                    Op::Sub => apply(Op::Sub, k, target)?,
+                    Op::Mul if k.is_zero() => return Err(ExprError::NoUniqueSolution),
+                    Op::Mul => apply(Op::Div, target, k)?,
+                    Op::Div if target.is_zero() => return Err(ExprError::DivisionByZero),
+                    Op::Div => apply(Op::Div, k, target)?,
+                };
+                b.invert(x)
+            }
+        }
+    }
+}
+
+// The value of the unknown that makes both sides equal. When the unknown
+// shows up more than once the sides may still be linear in it
+pub fn solve(left: &Expr, right: &Expr) -> Result<Rational, ExprError> {
+    let solved = match (left.contains_unknown(), right.contains_unknown()) {
+        (true, true) => Err(ExprError::NotLinear),
+        (false, false) => Err(ExprError::NoUnknown),
+        (true, false) => left.invert(right.evaluate()?),
+        (false, true) => right.invert(left.evaluate()?),
+    };
+    if solved != Err(ExprError::NotLinear) {
+        return solved;
+    }
+    // a1 x + b1 = a2 x + b2
+    let ((a1, b1), (a2, b2)) = match (left.linear(), right.linear()) {
+        (Ok(left), Ok(right)) => (left, right),
+        (Err(ExprError::Overflow), _) | (_, Err(ExprError::Overflow)) => {
+            return Err(ExprError::Overflow)
+        }
+        _ => return solved,
+    };
+    if a1 == a2 {
+        return solved;
+    }
+    apply(Op::Div, apply(Op::Sub, b2, b1)?, apply(Op::Sub, a1, a2)?)
+}
+
+// a * x + b as (a' * x + b') / d with whole numbers, None if they don't fit
+// or can't be negated
+fn whole_terms(a: Rational, b: Rational) -> Option<(i128, i128, i128)> {
+    let (p, q) = (a.denominator(), b.denominator());
+    let d = (p / gcd(p as u128, q as u128) as i128).checked_mul(q)?;
+    let a = a.numerator().checked_mul(d / p)?;
+    let b = b.numerator().checked_mul(d / q)?;
+    (a != i128::MIN && b != i128::MIN).then_some((a, b, d))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        expr::{apply, solve, Expr, ExprError},
+        rational::Rational,
+        Op,
+    };
+
+    fn number(n: i64) -> Box<Expr> {
+        Box::new(Expr::Number(Rational::from(n)))
+    }
+
+    fn unknown() -> Box<Expr> {
+        Box::new(Expr::Unknown("humn".to_string()))
+    }
+
+    fn operation(op: Op, a: Box<Expr>, b: Box<Expr>) -> Box<Expr> {
+        Box::new(Expr::Operation(op, a, b))
+    }
+
+    #[test]
+    fn solve_every_operation_on_both_sides() {
+        let (three, six) = (Rational::from(3), Rational::from(6));
+        for op in [Op::Add, Op::Sub, Op::Mul, Op::Div] {
+            // x op 6 and 6 op x for x = 3
+            let cases = [
+                (
+                    Expr::Operation(op, unknown(), number(6)),
+                    apply(op, three, six),
+                ),
+                (
+                    Expr::Operation(op, number(6), unknown()),
+                    apply(op, six, three),
+                ),
+            ];
+            for (expr, value) in cases {
+                let target = Expr::Number(value.unwrap());
+                assert_eq!(solve(&expr, &target), Ok(three), "{:?}", expr);
+                assert_eq!(solve(&target, &expr), Ok(three), "{:?}", expr);
+            }
+        }
+        let number = Expr::Number(three);
+        assert_eq!(solve(&number, &number), Err(ExprError::NoUnknown));
+    }
+
+    #[test]
+    fn fractions_and_failures() {
+        // (x - 7) / 2 = 4 / 3
+        let expr = operation(Op::Div, operation(Op::Sub, unknown(), number(7)), number(2));
+        let target = Expr::Number(Rational::new(4, 3));
+        assert_eq!(solve(&expr, &target), Ok(Rational::new(29, 3)));
+
+        let squared = Expr::Operation(Op::Mul, unknown(), unknown());
+        assert_eq!(solve(&squared, &target), Err(ExprError::NotLinear));
+        let zero = Expr::Operation(Op::Mul, unknown(), number(0));
+        assert_eq!(solve(&zero, &target), Err(ExprError::NoUniqueSolution));
+        let zero = Expr::Operation(Op::Mul, number(0), unknown());
+        assert_eq!(solve(&zero, &target), Err(ExprError::NoUniqueSolution));
+        let by_zero = Expr::Operation(Op::Div, unknown(), number(0));
+        assert_eq!(solve(&by_zero, &target), Err(ExprError::DivisionByZero));
+        assert_eq!(expr.evaluate(), Err(ExprError::Unknown("humn".to_string())));
+
+        let max = Expr::Number(Rational::new(i128::MAX, 1));
+        let less = Expr::Operation(Op::Add, unknown(), number(1));
+        assert_eq!(solve(&less, &max), Ok(Rational::new(i128::MAX - 1, 1)));
+        let more = Expr::Operation(Op::Sub, unknown(), number(1));
+        assert_eq!(solve(&more, &max), Err(ExprError::Overflow));
+        // 2x + max = x - 1
+        let twice = operation(Op::Add, unknown(), unknown());
+        let twice = Expr::Operation(Op::Add, twice, Box::new(max));
+        assert_eq!(solve(&twice, &more), Err(ExprError::Overflow));
+    }
+
+    #[test]
+    fn collect_linear_forms() {
+        // (x * 3 - 1) / 2 - (x - 3) / 2 * 0 + 2 - x / 4
+        let expr = operation(
+            Op::Sub,
+            operation(
+                Op::Add,
+                operation(
+                    Op::Sub,
+                    operation(
+                        Op::Div,
+                        operation(Op::Sub, operation(Op::Mul, unknown(), number(3)), number(1)),
+                        number(2),
+                    ),
+                    operation(
+                        Op::Mul,
+                        operation(Op::Div, operation(Op::Sub, unknown(), number(3)), number(2)),
+                        number(0),
+                    ),
+                ),
+                number(2),
+            ),
+            operation(Op::Div, unknown(), number(4)),
+        );
+        // 5/4 x + 3/2
+        let simple = Expr::Operation(
+            Op::Div,
+            operation(Op::Add, operation(Op::Mul, number(5), unknown()), number(6)),
+            number(4),
+        );
+        assert_eq!(expr.simplify(), simple);
+        let target = Expr::Number(Rational::from(11));
+        assert_eq!(solve(&expr, &target), solve(&simple, &target));
+
+        assert_eq!(solve(&expr, &expr), Err(ExprError::NotLinear));
+        let squared = Expr::Operation(Op::Mul, unknown(), unknown());
+        assert_eq!(squared.simplify(), squared);
+        let inverse = Expr::Operation(Op::Div, number(1), unknown());
+        assert_eq!(inverse.simplify(), inverse);
+        assert_eq!(
+            Expr::Operation(Op::Sub, unknown(), unknown()).simplify(),
+            *number(0)
+        );
+    }
+}
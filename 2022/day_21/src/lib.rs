@@ -0,0 +1,404 @@
+use aoc_common::{numbered_lines, Answer, Cursor, ParseError, ParseResult, Solution};
+use expr::{solve, Expr, ExprError};
+use std::{any::Any, collections::HashMap};
+use validate::{validate, Issue};
+
+mod expr;
+mod printer;
+mod rational;
+mod validate;
+
+pub use printer::Format;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Add,
+    Sub,
+    Div,
+    Mul,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Job {
+    Number(i64),
+    Operation((Op, String, String)),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Monkey {
+    name: String,
+    job: Job,
+}
+
+fn parse_monkey(number: usize, line: &str) -> ParseResult<Monkey> {
+    let mut cursor = Cursor::new(number, line);
+    let name = cursor.word()?.to_string();
+    cursor.tag(": ")?;
+    let job = if cursor.rest().starts_with(|c: char| c.is_ascii_digit()) {
+        Job::Number(cursor.number::<i64>()?)
+    } else {
+        let a = cursor.word()?.to_string();
+        cursor.tag(" ")?;
+        let ops = [
+            ("+", Op::Add),
+            ("-", Op::Sub),
+            ("/", Op::Div),
+            ("*", Op::Mul),
+        ];
+        let op = cursor.one_of("an operation", &ops)?;
+        cursor.tag(" ")?;
+        let b = cursor.word()?.to_string();
+        Job::Operation((op, a, b))
+    };
+    cursor.end()?;
+    Ok(Monkey { name, job })
+}
+
+fn parse_monkeys(input: &str) -> ParseResult<Vec<Monkey>> {
+    numbered_lines(input)
+        .map(|(number, line)| parse_monkey(number, line))
+        .collect()
+}
+
+// Where an issue shows up in the input, every monkey is on its own line
+fn issue_error(lines: &[&str], monkeys: &[Monkey], issue: Issue) -> ParseError {
+    let index_of = |name: &str| monkeys.iter().position(|m| m.name == name).unwrap();
+    let operand = |monkey: &str, name: &str| {
+        let index = index_of(monkey);
+        let column = lines[index].rfind(name).unwrap_or(0) + 1;
+        (index + 1, column, format!("\"{}\"", name))
+    };
+    let (line, column, expected, found) = match issue {
+        Issue::Undefined { monkey, name } => {
+            let (line, column, found) = operand(&monkey, &name);
+            (line, column, "a known monkey", found)
+        }
+        Issue::Cycle(names) => {
+            // the job starts after "name: "
+            let column = names[0].len() + 3;
+            let line = index_of(&names[0]) + 1;
+            let found = format!("\"{}\"", names.join(" -> "));
+            (line, column, "a job without a cycle", found)
+        }
+        Issue::Unused(name) => {
+            let line = index_of(&name) + 1;
+            let found = format!("\"{}\"", name);
+            (line, 1, "a monkey that root waits for", found)
+        }
+        Issue::DivisionByZero { monkey, divisor } => {
+            let (line, column, found) = operand(&monkey, &divisor);
+            (line, column, "a divisor that isn't zero", found)
+        }
+        Issue::DivisionByUnknown { monkey, divisor } => {
+            let (line, column, found) = operand(&monkey, &divisor);
+            (line, column, "a divisor that doesn't depend on humn", found)
+        }
+    };
+    ParseError::new(line, column, expected, &found)
+}
+
+// Only the issues that keep the jobs from being worked out are errors, with
+// humn as the unknown the input also needs a humn that root waits for
+fn read_input(input: &str, unknown: Option<&str>) -> ParseResult<Vec<Monkey>> {
+    let monkeys = parse_monkeys(input)?;
+
+    let lines: Vec<&str> = input.lines().collect();
+    let fatal = validate(&monkeys)
+        .into_iter()
+        .find(|issue| issue.is_fatal(unknown.is_some()));
+    if let Some(issue) = fatal {
+        return Err(issue_error(&lines, &monkeys, issue));
+    }
+    let names = ["root"].into_iter().chain(unknown);
+    for name in names {
+        if !monkeys.iter().any(|m| m.name == name) {
+            let expected = format!("a \"{}\" monkey", name);
+            return Err(ParseError::end_of_input(monkeys.len() + 1, &expected));
+        }
+    }
+    Ok(monkeys)
+}
+
+fn monkey_map(items: &[Monkey]) -> HashMap<&str, &Monkey> {
+    items
+        .iter()
+        .map(|monkey| (monkey.name.as_str(), monkey))
+        .collect()
+}
+
+fn part_one(items: &[Monkey]) -> Result<i64, ExprError> {
+    let value = Expr::build(&monkey_map(items), "root", None)?.evaluate()?;
+    value.to_integer().ok_or(ExprError::NotAnInteger(value))
+}
+
+// root compares its two monkeys and humn is the unknown that makes them equal
+fn equation(monkeys: &HashMap<&str, &Monkey>) -> Result<(Expr, Expr), ExprError> {
+    let (a, b) = match &monkeys["root"].job {
+        Job::Operation((_, a, b)) => (a, b),
+        Job::Number(_) => return Err(ExprError::NoUnknown),
+    };
+    let left = Expr::build(monkeys, a, Some("humn"))?;
+    let right = Expr::build(monkeys, b, Some("humn"))?;
+    Ok((left, right))
+}
+
+fn part_two(items: &[Monkey]) -> Result<i64, ExprError> {
+    let (left, right) = equation(&monkey_map(items))?;
+    let value = solve(&left, &right)?;
+    value.to_integer().ok_or(ExprError::NotAnInteger(value))
+}
+
+// Points at the monkey whose job can't be worked out: a division by zero,
+// humn times humn, a multiplication by zero that loses humn or a number that
+// gets too large. Anything else is blamed on root
+fn expr_error(items: &[Monkey], unknown: Option<&str>, error: ExprError) -> ParseError {
+    let monkeys = monkey_map(items);
+    let build = |name: &str| Expr::build(&monkeys, name, unknown);
+    let is_zero = |expr: &Expr| expr.evaluate().is_ok_and(|x| x.is_zero());
+    let culprit = items.iter().position(|monkey| {
+        let Job::Operation((op, a, b)) = &monkey.job else {
+            return false;
+        };
+        let (Ok(a), Ok(b)) = (build(a), build(b)) else {
+            return false;
+        };
+        let (a_unknown, b_unknown) = (a.contains_unknown(), b.contains_unknown());
+        match (&error, op) {
+            (ExprError::DivisionByZero, Op::Div) => is_zero(&b),
+            (ExprError::NoUniqueSolution, Op::Mul) => {
+                (a_unknown && is_zero(&b)) || (b_unknown && is_zero(&a))
+            }
+            (ExprError::NotLinear, Op::Mul) => a_unknown && b_unknown,
+            (ExprError::Overflow, _) => build(&monkey.name) == Err(ExprError::Overflow),
+            _ => false,
+        }
+    });
+    let index = culprit
+        .or_else(|| items.iter().position(|m| m.name == "root"))
+        .unwrap_or(0);
+    // the job starts after "name: "
+    let column = items[index].name.len() + 3;
+    let expected = "a job with a solution";
+    ParseError::new(index + 1, column, expected, &error.to_string())
+}
+
+// The equation of root with everything but humn folded, for debugging
+pub fn render_equation(input: &str, format: Format) -> ParseResult<String> {
+    let monkeys = read_input(input, Some("humn"))?;
+    let (left, right) = equation(&monkey_map(&monkeys))
+        .map_err(|error| expr_error(&monkeys, Some("humn"), error))?;
+    Ok(printer::render(&left.simplify(), &right.simplify(), format))
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn parse(&self, input: &str) -> Option<ParseResult<Box<dyn Any>>> {
+        Some(read_input(input, None).map(|x| Box::new(x) as Box<dyn Any>))
+    }
+
+    fn part_one(&self, input: &str) -> ParseResult<Answer> {
+        let monkeys = read_input(input, None)?;
+        let answer = part_one(&monkeys).map_err(|error| expr_error(&monkeys, None, error))?;
+        Ok(answer.into())
+    }
+
+    fn part_two(&self, input: &str) -> ParseResult<Answer> {
+        let monkeys = read_input(input, Some("humn"))?;
+        let answer =
+            part_two(&monkeys).map_err(|error| expr_error(&monkeys, Some("humn"), error))?;
+        Ok(answer.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Answer, ParseError};
+
+    use crate::{
+        expr::ExprError, parse_monkey, part_one, part_two, read_input, render_equation, Day21,
+        Format, Job, Monkey, Op,
+    };
+    use aoc_common::Solution;
+
+    #[test]
+    fn monkey_parse_from() {
+        let m = parse_monkey(1, "root: pppw + sjmn").unwrap();
+        assert_eq!(
+            m,
+            Monkey {
+                name: "root".to_string(),
+                job: Job::Operation((Op::Add, "pppw".to_string(), "sjmn".to_string()))
+            }
+        );
+
+        let m = parse_monkey(1, "dbpl: 5").unwrap();
+        assert_eq!(
+            m,
+            Monkey {
+                name: "dbpl".to_string(),
+                job: Job::Number(5),
+            }
+        );
+
+        let m = parse_monkey(1, "ptdq: humn - dvpt").unwrap();
+        assert_eq!(
+            m,
+            Monkey {
+                name: "ptdq".to_string(),
+                job: Job::Operation((Op::Sub, "humn".to_string(), "dvpt".to_string()))
+            }
+        );
+
+        let m = parse_monkey(1, "sjmn: drzm * dbpl").unwrap();
+        assert_eq!(
+            m,
+            Monkey {
+                name: "sjmn".to_string(),
+                job: Job::Operation((Op::Mul, "drzm".to_string(), "dbpl".to_string()))
+            }
+        );
+
+        let m = parse_monkey(1, "pppw: cczh / lfqf").unwrap();
+        assert_eq!(
+            m,
+            Monkey {
+                name: "pppw".to_string(),
+                job: Job::Operation((Op::Div, "cczh".to_string(), "lfqf".to_string()))
+            }
+        );
+    }
+
+    #[test]
+    fn monkey_parse_errors() {
+        let error = parse_monkey(3, "root: pppw % sjmn").unwrap_err();
+        assert_eq!(error, ParseError::new(3, 12, "an operation", "\"%\""));
+
+        let input = "root: pppw + sjmn\npppw: 2\n";
+        let error = read_input(input, None).unwrap_err();
+        assert_eq!(error, ParseError::new(1, 14, "a known monkey", "\"sjmn\""));
+
+        let input = "root: pppw + sjmn\npppw: 2\nsjmn: sjmn * pppw\n";
+        let error = read_input(input, None).unwrap_err();
+        let found = "\"sjmn -> sjmn\"";
+        assert_eq!(error, ParseError::new(3, 7, "a job without a cycle", found));
+
+        let input = "root: pppw + sjmn\npppw: 2\nsjmn: pppw / zero\nzero: 0\nhumn: 5\n";
+        let error = read_input(input, Some("humn")).unwrap_err();
+        let found = "\"humn\"";
+        assert_eq!(
+            error,
+            ParseError::new(5, 1, "a monkey that root waits for", found)
+        );
+        // the division by zero is only found by evaluating it
+        let input = "root: pppw + sjmn\npppw: 2\nsjmn: pppw / zero\nzero: 0\n";
+        let error = Day21.part_one(input).unwrap_err();
+        let found = "division by zero";
+        assert_eq!(error, ParseError::new(3, 7, "a job with a solution", found));
+    }
+
+    #[test]
+    fn part_one_without_humn_issues() {
+        // root doesn't need humn, or there is no humn at all
+        let lines = ["root: pppw + sjmn", "pppw: 2", "sjmn: 3", "humn: 5"];
+        assert_eq!(Day21.part_one(&lines.join("\n")), Ok(Answer::Number(5)));
+        let error = Day21.part_two(&lines.join("\n")).unwrap_err();
+        let found = "\"humn\"";
+        assert_eq!(
+            error,
+            ParseError::new(4, 1, "a monkey that root waits for", found)
+        );
+        assert_eq!(
+            Day21.part_one(&lines[..3].join("\n")),
+            Ok(Answer::Number(5))
+        );
+        let error = Day21.part_two(&lines[..3].join("\n")).unwrap_err();
+        assert_eq!(error, ParseError::end_of_input(4, "a \"humn\" monkey"));
+
+        // a divisor that depends on humn is only a problem when solving for it
+        let lines = [
+            "root: pppw + sjmn",
+            "pppw: 6",
+            "sjmn: pppw / humn",
+            "humn: 3",
+        ];
+        assert_eq!(Day21.part_one(&lines.join("\n")), Ok(Answer::Number(8)));
+        let error = Day21.part_two(&lines.join("\n")).unwrap_err();
+        let expected = "a divisor that doesn't depend on humn";
+        assert_eq!(error, ParseError::new(3, 14, expected, "\"humn\""));
+    }
+
+    #[test]
+    fn example_parts() {
+        let monkeys = read_input(include_str!("../test.txt"), Some("humn")).unwrap();
+        assert_eq!(part_one(&monkeys), Ok(152));
+        assert_eq!(part_two(&monkeys), Ok(301));
+    }
+
+    #[test]
+    fn humn_has_to_be_a_whole_number() {
+        // humn / 2 = 3 / 4 needs humn = 3/2
+        let input = [
+            "root: half + frac",
+            "half: humn / two",
+            "frac: thre / four",
+            "humn: 1",
+            "two: 2",
+            "thre: 3",
+            "four: 4",
+        ];
+        let monkeys = read_input(&input.join("\n"), Some("humn")).unwrap();
+        let error = part_two(&monkeys).unwrap_err();
+        assert_eq!(error.to_string(), "3/2 is not a whole number");
+        assert!(matches!(error, ExprError::NotAnInteger(_)));
+    }
+
+    #[test]
+    fn errors_point_at_the_monkey() {
+        let lines = [
+            "root: aaaa + bbbb",
+            "aaaa: humn * humn",
+            "bbbb: cccc * zero",
+            "cccc: 4",
+            "humn: 2",
+            "zero: 0",
+        ];
+        let error = Day21.part_two(&lines.join("\n")).unwrap_err();
+        let found = "the unknown is on both sides";
+        assert_eq!(error, ParseError::new(2, 7, "a job with a solution", found));
+
+        // humn * 0 = 0 holds for any humn
+        let input = lines.join("\n").replace("humn * humn", "humn * zero");
+        let error = Day21.part_two(&input).unwrap_err();
+        let found = "the unknown times zero has no unique solution";
+        assert_eq!(error, ParseError::new(2, 7, "a job with a solution", found));
+
+        let lines = [
+            "root: cube + one",
+            "squa: nine * nine",
+            "cube: squa * nine",
+            "nine: 9000000000000000000",
+            "one: 1",
+            "humn: 2",
+        ];
+        let error = Day21.part_one(&lines.join("\n")).unwrap_err();
+        let found = "a number doesn't fit in 128 bits";
+        assert_eq!(error, ParseError::new(3, 7, "a job with a solution", found));
+    }
+
+    #[test]
+    fn render_the_example() {
+        let input = include_str!("../test.txt");
+        let text = render_equation(input, Format::Text).unwrap();
+        assert_eq!(text, "(humn - 1)/2 = 150");
+        let latex = render_equation(input, Format::Latex).unwrap();
+        assert_eq!(latex, "\\frac{\\mathit{humn} - 1}{2} = 150");
+
+        let dot = render_equation(input, Format::Dot).unwrap();
+        assert!(dot.starts_with("digraph {") && dot.ends_with('}'));
+        assert!(dot.contains("[label=\"humn\", shape=box]"));
+        // =, /, -, humn, 1, 2 and 150
+        assert_eq!(dot.matches("label").count(), 7);
+        assert_eq!(dot.matches("->").count(), 6);
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{expr::Expr, rational::Rational, Op};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Latex,
+    Dot,
+}
+
+// How strongly an expression holds together, operands that bind weaker
+// than their operation need parentheses
+fn precedence(expr: &Expr, format: Format) -> u8 {
+    match expr {
+        Expr::Number(n) if n.numerator() < 0 => 1,
+        Expr::Number(n) if !n.is_integer() && format == Format::Text => 2,
+        Expr::Operation(Op::Add | Op::Sub, _, _) => 1,
+        // a fraction bar groups on its own
+        Expr::Operation(Op::Div, _, _) if format == Format::Latex => 3,
+        Expr::Operation(_, _, _) => 2,
+        _ => 3,
+    }
+}
+
+fn number(n: &Rational, format: Format) -> String {
+    if format == Format::Text || n.is_integer() {
+        return n.to_string();
+    }
+    let sign = if n.numerator() < 0 { "-" } else { "" };
+    let (p, q) = (n.numerator().abs(), n.denominator());
+    format!("{}\\frac{{{}}}{{{}}}", sign, p, q)
+}
+
+fn infix(expr: &Expr, format: Format) -> String {
+    let (op, a, b) = match expr {
+        Expr::Number(n) => return number(n, format),
+        Expr::Unknown(name) if format == Format::Latex => return format!("\\mathit{{{}}}", name),
+        Expr::Unknown(name) => return name.clone(),
+        Expr::Operation(op, a, b) => (*op, a, b),
+    };
+    if op == Op::Div && format == Format::Latex {
+        return format!("\\frac{{{}}}{{{}}}", infix(a, format), infix(b, format));
+    }
+
+    let outer = precedence(expr, format);
+    let wrap = |operand: &Expr, parentheses: bool| {
+        let text = infix(operand, format);
+        if parentheses {
+            format!("({})", text)
+        } else {
+            text
+        }
+    };
+    // a - b and a / b don't regroup, so an equally strong right side keeps its parentheses
+    let inner = precedence(b, format);
+    // a leading minus sign reads the same with or without them
+    let negative = matches!(&**a, Expr::Number(n) if n.numerator() < 0);
+    let left = wrap(a, precedence(a, format) < outer && !negative);
+    let right = wrap(
+        b,
+        inner < outer || (inner == outer && matches!(op, Op::Sub | Op::Div)),
+    );
+    let symbol = match (op, format) {
+        (Op::Add, _) => " + ",
+        (Op::Sub, _) => " - ",
+        (Op::Mul, Format::Latex) => " \\cdot ",
+        (Op::Mul, _) => "*",
+        (Op::Div, _) => "/",
+    };
+    format!("{}{}{}", left, symbol, right)
+}
+
+// Adds the nodes of an expression to a Graphviz graph, returns the id of the top one
+fn dot_nodes(expr: &Expr, lines: &mut Vec<String>, next_id: &mut usize) -> usize {
+    let id = *next_id;
+    *next_id += 1;
+    match expr {
+        Expr::Number(n) => lines.push(format!("    n{} [label=\"{}\"];", id, n)),
+        Expr::Unknown(name) => lines.push(format!("    n{} [label=\"{}\", shape=box];", id, name)),
+        Expr::Operation(op, a, b) => {
+            let symbol = match op {
+                Op::Add => "+",
+                Op::Sub => "-",
+                Op::Mul => "*",
+                Op::Div => "/",
+            };
+            lines.push(format!("    n{} [label=\"{}\", shape=circle];", id, symbol));
+            for operand in [a, b] {
+                let child = dot_nodes(operand, lines, next_id);
+                lines.push(format!("    n{} -> n{};", id, child));
+            }
+        }
+    }
+    id
+}
+
+// Both sides of an equation in one of the formats, the expressions are
+// printed as they are so they should be simplified first
+pub fn render(left: &Expr, right: &Expr, format: Format) -> String {
+    if format != Format::Dot {
+        return format!("{} = {}", infix(left, format), infix(right, format));
+    }
+    let mut lines = vec!["digraph {".to_string(), "    n0 [label=\"=\"];".to_string()];
+    let mut next_id = 1;
+    for side in [left, right] {
+        let child = dot_nodes(side, &mut lines, &mut next_id);
+        lines.push(format!("    n0 -> n{};", child));
+    }
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        expr::Expr,
+        printer::{render, Format},
+        rational::Rational,
+        Op,
+    };
+
+    fn number(n: i128, d: i128) -> Box<Expr> {
+        Box::new(Expr::Number(Rational::new(n, d)))
+    }
+
+    fn x() -> Box<Expr> {
+        Box::new(Expr::Unknown("x".to_string()))
+    }
+
+    fn operation(op: Op, a: Box<Expr>, b: Box<Expr>) -> Box<Expr> {
+        Box::new(Expr::Operation(op, a, b))
+    }
+
+    fn text(expr: Box<Expr>) -> String {
+        render(&expr, &number(0, 1), Format::Text)
+    }
+
+    #[test]
+    fn minimal_parentheses() {
+        let sum = || operation(Op::Add, x(), number(1, 1));
+        let product = || operation(Op::Mul, x(), number(2, 1));
+        assert_eq!(
+            text(operation(Op::Sub, sum(), sum())),
+            "x + 1 - (x + 1) = 0"
+        );
+        assert_eq!(text(operation(Op::Add, sum(), sum())), "x + 1 + x + 1 = 0");
+        assert_eq!(
+            text(operation(Op::Mul, sum(), product())),
+            "(x + 1)*x*2 = 0"
+        );
+        assert_eq!(
+            text(operation(Op::Div, product(), product())),
+            "x*2/(x*2) = 0"
+        );
+        assert_eq!(
+            text(operation(Op::Sub, product(), number(-3, 1))),
+            "x*2 - (-3) = 0"
+        );
+        assert_eq!(text(operation(Op::Mul, x(), number(1, 2))), "x*1/2 = 0");
+        assert_eq!(text(operation(Op::Mul, number(-3, 2), x())), "-3/2*x = 0");
+        assert_eq!(text(operation(Op::Div, x(), number(1, 2))), "x/(1/2) = 0");
+        assert_eq!(text(operation(Op::Add, number(1, 2), x())), "1/2 + x = 0");
+    }
+
+    #[test]
+    fn latex_fractions() {
+        let expr = operation(
+            Op::Div,
+            operation(Op::Mul, operation(Op::Sub, x(), number(1, 1)), number(3, 1)),
+            number(2, 1),
+        );
+        assert_eq!(
+            render(&expr, &number(-7, 2), Format::Latex),
+            "\\frac{(\\mathit{x} - 1) \\cdot 3}{2} = -\\frac{7}{2}"
+        );
+    }
+}
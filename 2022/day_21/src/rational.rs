@@ -0,0 +1,229 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::Neg,
+};
+
+// A fraction kept in lowest terms with a positive denominator, so equal
+// numbers have equal fields. The numerator is never i128::MIN so negating
+// can't overflow, the arithmetic is checked like it is for integers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+pub fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// The fields of a Rational are above i128::MIN, so their gcd fits
+fn common_factor(a: i128, b: i128) -> i128 {
+    gcd(a.unsigned_abs(), b.unsigned_abs()) as i128
+}
+
+impl Rational {
+    pub fn new(numerator: i128, denominator: i128) -> Rational {
+        assert!(denominator != 0, "a denominator of zero");
+        Rational::checked_new(numerator, denominator).expect("a numerator of i128::MIN")
+    }
+
+    // None for a denominator of zero or a fraction that doesn't fit once
+    // it is reduced
+    pub fn checked_new(numerator: i128, denominator: i128) -> Option<Rational> {
+        if denominator == 0 {
+            return None;
+        }
+        let (n, d) = (numerator.unsigned_abs(), denominator.unsigned_abs());
+        let divisor = gcd(n, d);
+        let n = i128::try_from(n / divisor).ok()?;
+        let d = i128::try_from(d / divisor).ok()?;
+        let negative = (numerator < 0) != (denominator < 0);
+        Some(Rational {
+            numerator: if negative { -n } else { n },
+            denominator: d,
+        })
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    pub fn to_integer(self) -> Option<i64> {
+        if !self.is_integer() {
+            return None;
+        }
+        i64::try_from(self.numerator).ok()
+    }
+
+    pub fn checked_add(self, other: Rational) -> Option<Rational> {
+        // over the least common denominator
+        let divisor = common_factor(self.denominator, other.denominator);
+        let (x, y) = (self.denominator / divisor, other.denominator / divisor);
+        let numerator = self
+            .numerator
+            .checked_mul(y)?
+            .checked_add(other.numerator.checked_mul(x)?)?;
+        Rational::checked_new(numerator, self.denominator.checked_mul(y)?)
+    }
+
+    pub fn checked_sub(self, other: Rational) -> Option<Rational> {
+        self.checked_add(-other)
+    }
+
+    pub fn checked_mul(self, other: Rational) -> Option<Rational> {
+        // reduced crosswise first, so the products are already in lowest terms
+        let a = common_factor(self.numerator, other.denominator);
+        let b = common_factor(other.numerator, self.denominator);
+        let numerator = (self.numerator / a).checked_mul(other.numerator / b)?;
+        let denominator = (self.denominator / b).checked_mul(other.denominator / a)?;
+        Rational::checked_new(numerator, denominator)
+    }
+
+    pub fn checked_div(self, other: Rational) -> Option<Rational> {
+        if other.is_zero() {
+            return None;
+        }
+        let reciprocal = Rational {
+            numerator: other.denominator * other.numerator.signum(),
+            denominator: other.numerator.abs(),
+        };
+        self.checked_mul(reciprocal)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational {
+            numerator: value as i128,
+            denominator: 1,
+        }
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl Ord for Rational {
+    // Compares the whole parts and then the fractional parts by their
+    // reciprocals, like a continued fraction, so nothing is multiplied
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = (self.numerator, other.numerator);
+        let (c, d) = (self.denominator, other.denominator);
+        let whole = a.div_euclid(c).cmp(&b.div_euclid(d));
+        if whole != Ordering::Equal {
+            return whole;
+        }
+        let (x, y) = (a.rem_euclid(c), b.rem_euclid(d));
+        match (x, y) {
+            (0, 0) => Ordering::Equal,
+            (0, _) => Ordering::Less,
+            (_, 0) => Ordering::Greater,
+            // x/c < y/d exactly when c/x > d/y
+            _ => {
+                let inverse = |numerator, denominator| Rational {
+                    numerator,
+                    denominator,
+                };
+                inverse(d, y).cmp(&inverse(c, x))
+            }
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rational::Rational;
+
+    #[test]
+    fn lowest_terms() {
+        assert_eq!(Rational::new(6, -4), Rational::new(-3, 2));
+        assert_eq!(Rational::new(0, -7), Rational::from(0));
+        assert_eq!(Rational::new(-3, 2).to_string(), "-3/2");
+        assert_eq!(Rational::new(8, 4).to_string(), "2");
+        assert_eq!(Rational::new(8, 4).to_integer(), Some(2));
+        assert_eq!(Rational::new(7, 4).to_integer(), None);
+        assert_eq!(Rational::new(-14, 8).numerator(), -7);
+        assert_eq!(Rational::new(-14, 8).denominator(), 4);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let half = Rational::new(1, 2);
+        let third = Rational::new(1, 3);
+        assert_eq!(half.checked_add(third), Some(Rational::new(5, 6)));
+        assert_eq!(half.checked_sub(third), Some(Rational::new(1, 6)));
+        assert_eq!(half.checked_mul(third), Some(Rational::new(1, 6)));
+        assert_eq!(half.checked_div(third), Some(Rational::new(3, 2)));
+        let seven = Rational::from(7);
+        assert_eq!(
+            seven.checked_div(Rational::from(2)),
+            Some(Rational::new(7, 2))
+        );
+        assert_eq!(seven.checked_div(Rational::from(0)), None);
+        assert!(third < half && -half < third);
+        assert!(Rational::new(-7, 2) < Rational::from(-3));
+        assert!(Rational::new(5, 7) > Rational::new(7, 10));
+    }
+
+    #[test]
+    fn overflow() {
+        let max = Rational::new(i128::MAX, 1);
+        assert_eq!(max.checked_add(Rational::from(1)), None);
+        assert_eq!(max.checked_mul(Rational::from(2)), None);
+        assert_eq!((-max).checked_sub(Rational::from(2)), None);
+        assert_eq!(Rational::checked_new(i128::MIN, 1), None);
+        assert_eq!(
+            Rational::checked_new(i128::MIN, 2),
+            Some(Rational::new(-(1 << 126), 1))
+        );
+
+        // the factors cancel before they are multiplied
+        let tiny = Rational::new(1, i128::MAX);
+        assert_eq!(max.checked_mul(tiny), Some(Rational::from(1)));
+        assert_eq!(max.checked_div(max), Some(Rational::from(1)));
+        let nearly = Rational::new(i128::MAX - 1, i128::MAX);
+        assert!(nearly < Rational::from(1) && tiny < nearly);
+        assert!(Rational::new(i128::MAX, i128::MAX - 1) > Rational::from(1));
+    }
+}
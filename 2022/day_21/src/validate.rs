@@ -0,0 +1,264 @@
+use crate::{expr::apply, rational::Rational, Job, Monkey, Op};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    // a monkey waits for a name that no monkey has
+    Undefined { monkey: String, name: String },
+    // monkeys that wait for each other, the first one again at the end
+    Cycle(Vec<String>),
+    // root never waits for it
+    Unused(String),
+    // the divisor is zero with the numbers of the input
+    DivisionByZero { monkey: String, divisor: String },
+    // the divisor depends on humn, some number for humn may make it zero
+    DivisionByUnknown { monkey: String, divisor: String },
+}
+
+impl Issue {
+    // Whether the jobs can't be worked out with it. A monkey that root doesn't
+    // need and a divisor that depends on humn only matter once humn is the
+    // unknown, a division by zero is reported where it's evaluated
+    pub fn is_fatal(&self, solving_for_humn: bool) -> bool {
+        match self {
+            Issue::Undefined { .. } | Issue::Cycle(_) => true,
+            Issue::Unused(_) | Issue::DivisionByUnknown { .. } => solving_for_humn,
+            Issue::DivisionByZero { .. } => false,
+        }
+    }
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Issue::Undefined { monkey, name } => {
+                write!(f, "\"{}\" waits for an unknown monkey \"{}\"", monkey, name)
+            }
+            Issue::Cycle(names) => write!(f, "monkeys wait for each other: {}", names.join(" -> ")),
+            Issue::Unused(name) => write!(f, "root doesn't need \"{}\"", name),
+            Issue::DivisionByZero { monkey, divisor } => {
+                write!(f, "\"{}\" divides by zero from \"{}\"", monkey, divisor)
+            }
+            Issue::DivisionByUnknown { monkey, divisor } => {
+                write!(
+                    f,
+                    "\"{}\" divides by \"{}\" that depends on humn",
+                    monkey, divisor
+                )
+            }
+        }
+    }
+}
+
+fn operands(monkey: &Monkey) -> Vec<&str> {
+    match &monkey.job {
+        Job::Number(_) => vec![],
+        Job::Operation((_, a, b)) => vec![a.as_str(), b.as_str()],
+    }
+}
+
+// Every monkey after the ones it waits for, in the order of the input where
+// that is possible. Monkeys that wait for a cycle are left out
+fn topological_order<'a>(
+    monkeys: &'a [Monkey],
+    by_name: &HashMap<&str, &'a Monkey>,
+) -> (Vec<&'a str>, Vec<Issue>) {
+    let mut order = vec![];
+    let mut issues = vec![];
+    // monkeys on the stack are in progress, finished ones are done or blocked
+    let mut in_progress = HashSet::new();
+    let mut done = HashSet::new();
+    let mut blocked = HashSet::new();
+
+    for monkey in monkeys {
+        let mut stack = vec![(monkey.name.as_str(), 0)];
+        while let Some((name, next)) = stack.pop() {
+            if next == 0 && (done.contains(name) || blocked.contains(name)) {
+                continue;
+            }
+            in_progress.insert(name);
+            let waits_for = by_name.get(name).map(|x| operands(x)).unwrap_or_default();
+            if let Some(operand) = waits_for.get(next) {
+                stack.push((name, next + 1));
+                // a cycle that was reported already blocks its monkeys
+                if in_progress.contains(operand) && !blocked.contains(operand) {
+                    let start = stack.iter().position(|(x, _)| x == operand).unwrap();
+                    let mut cycle: Vec<String> =
+                        stack[start..].iter().map(|(x, _)| x.to_string()).collect();
+                    cycle.push(operand.to_string());
+                    issues.push(Issue::Cycle(cycle));
+                    blocked.extend(stack[start..].iter().map(|(x, _)| *x));
+                } else if by_name.contains_key(operand) {
+                    stack.push((operand, 0));
+                }
+                continue;
+            }
+            in_progress.remove(name);
+            let ready = waits_for
+                .iter()
+                .all(|x| done.contains(x) || !by_name.contains_key(x));
+            if ready && !blocked.contains(name) {
+                done.insert(name);
+                order.push(name);
+            } else {
+                blocked.insert(name);
+            }
+        }
+    }
+    (order, issues)
+}
+
+// Checks the jobs before anything is evaluated, every problem is reported
+// with the names of the monkeys involved
+pub fn validate(monkeys: &[Monkey]) -> Vec<Issue> {
+    let by_name: HashMap<&str, &Monkey> = monkeys.iter().map(|x| (x.name.as_str(), x)).collect();
+    let mut issues = vec![];
+    for monkey in monkeys {
+        for name in operands(monkey) {
+            if !by_name.contains_key(name) {
+                issues.push(Issue::Undefined {
+                    monkey: monkey.name.clone(),
+                    name: name.to_string(),
+                });
+            }
+        }
+    }
+
+    let (order, cycles) = topological_order(monkeys, &by_name);
+    issues.extend(cycles);
+
+    if by_name.contains_key("root") {
+        let mut needed = HashSet::from(["root"]);
+        let mut queue = vec!["root"];
+        while let Some(name) = queue.pop() {
+            for operand in by_name.get(name).map(|x| operands(x)).unwrap_or_default() {
+                if needed.insert(operand) {
+                    queue.push(operand);
+                }
+            }
+        }
+        for monkey in monkeys.iter().filter(|x| !needed.contains(x.name.as_str())) {
+            issues.push(Issue::Unused(monkey.name.clone()));
+        }
+    }
+
+    // the values of the input and whether they depend on humn, a monkey that
+    // waits for a division by zero has no value
+    let mut values: HashMap<&str, (Option<Rational>, bool)> = HashMap::new();
+    for name in order {
+        let value = match &by_name[name].job {
+            Job::Number(n) => (Some(Rational::from(*n)), name == "humn"),
+            Job::Operation((op, a, b)) => {
+                let value_of = |name: &String| values.get(name.as_str()).copied();
+                let (x, x_humn) = value_of(a).unwrap_or((None, false));
+                let (y, y_humn) = value_of(b).unwrap_or((None, false));
+                if *op == Op::Div {
+                    let (monkey, divisor) = (name.to_string(), b.clone());
+                    if y.is_some_and(|y| y.is_zero()) {
+                        issues.push(Issue::DivisionByZero {
+                            monkey: monkey.clone(),
+                            divisor: divisor.clone(),
+                        });
+                    }
+                    if y_humn {
+                        issues.push(Issue::DivisionByUnknown { monkey, divisor });
+                    }
+                }
+                let value = x.zip(y).and_then(|(x, y)| apply(*op, x, y).ok());
+                (value, x_humn || y_humn)
+            }
+        };
+        values.insert(name, value);
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parse_monkey,
+        validate::{topological_order, validate, Issue},
+        Monkey,
+    };
+    use std::collections::HashMap;
+
+    fn monkeys(lines: &[&str]) -> Vec<Monkey> {
+        let parse = |(number, line)| parse_monkey(number + 1, line).unwrap();
+        lines.iter().copied().enumerate().map(parse).collect()
+    }
+
+    fn issues(lines: &[&str]) -> Vec<String> {
+        validate(&monkeys(lines))
+            .iter()
+            .map(|x| x.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn order_of_the_example() {
+        let monkeys = monkeys(&include_str!("../test.txt").lines().collect::<Vec<_>>());
+        let by_name: HashMap<&str, &Monkey> =
+            monkeys.iter().map(|x| (x.name.as_str(), x)).collect();
+        let (order, issues) = topological_order(&monkeys, &by_name);
+        assert!(issues.is_empty());
+        assert_eq!(order.len(), monkeys.len());
+        assert_eq!(order.last(), Some(&"root"));
+        let index: HashMap<&str, usize> = order.iter().enumerate().map(|(i, x)| (*x, i)).collect();
+        for monkey in &monkeys {
+            if let crate::Job::Operation((_, a, b)) = &monkey.job {
+                assert!(index[a.as_str()] < index[monkey.name.as_str()]);
+                assert!(index[b.as_str()] < index[monkey.name.as_str()]);
+            }
+        }
+        assert!(validate(&monkeys).is_empty());
+    }
+
+    #[test]
+    fn cycles_and_missing_monkeys() {
+        let found = issues(&[
+            "root: aaaa + bbbb",
+            "aaaa: bbbb * cccc",
+            "bbbb: 3",
+            "cccc: dddd - eeee",
+            "dddd: aaaa / bbbb",
+            "eeee: ffff + ffff",
+            "ffff: ffff - bbbb",
+            "gggg: hhhh + bbbb",
+        ]);
+        assert_eq!(
+            found,
+            [
+                "\"gggg\" waits for an unknown monkey \"hhhh\"",
+                "monkeys wait for each other: aaaa -> cccc -> dddd -> aaaa",
+                "monkeys wait for each other: ffff -> ffff",
+                "root doesn't need \"gggg\"",
+            ]
+        );
+        let found = validate(&monkeys(&["root: root + root"]));
+        assert_eq!(found, [Issue::Cycle(vec!["root".to_string(); 2])]);
+    }
+
+    #[test]
+    fn divisions() {
+        let found = issues(&[
+            "root: aaaa + bbbb",
+            "aaaa: dddd / zero",
+            "bbbb: dddd / cccc",
+            "cccc: humn - dddd",
+            "dddd: 5",
+            "humn: 5",
+            "zero: dddd - dddd",
+        ]);
+        assert_eq!(
+            found,
+            [
+                "\"aaaa\" divides by zero from \"zero\"",
+                "\"bbbb\" divides by zero from \"cccc\"",
+                "\"bbbb\" divides by \"cccc\" that depends on humn",
+            ]
+        );
+    }
+}
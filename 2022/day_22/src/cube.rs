@@ -0,0 +1,363 @@
+use crate::{Board, Edge, Point, Shift, Tile};
+use std::{
+    collections::VecDeque,
+    error::Error,
+    fmt::{self, Display},
+};
+
+type Vec3 = [i32; 3];
+
+// right, down, left and up on the board
+const SIDES: [Shift; 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FoldError {
+    // the tiles don't make six squares of the same size
+    Size { tiles: usize },
+    // a tile of a face square is missing
+    PartialFace(Point),
+    FaceCount(usize),
+    // the face doesn't touch the others
+    Disconnected(Point),
+    // two faces end up on the same side of the cube, the net isn't one of the 11
+    Overlap(Point, Point),
+}
+
+impl FoldError {
+    // A tile to point at on the board
+    pub fn position(&self) -> Point {
+        match self {
+            FoldError::Size { .. } | FoldError::FaceCount(_) => (1, 1),
+            FoldError::PartialFace(point)
+            | FoldError::Disconnected(point)
+            | FoldError::Overlap(_, point) => *point,
+        }
+    }
+}
+
+impl Display for FoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FoldError::Size { tiles } => write!(f, "{} tiles don't make six square faces", tiles),
+            FoldError::PartialFace(_) => write!(f, "a face with missing tiles"),
+            FoldError::FaceCount(count) => write!(f, "{} faces instead of 6", count),
+            FoldError::Disconnected(_) => write!(f, "a face that isn't connected to the others"),
+            FoldError::Overlap((x, y), _) => {
+                write!(
+                    f,
+                    "a face on the same side of the cube as the one at {},{}",
+                    x, y
+                )
+            }
+        }
+    }
+}
+
+impl Error for FoldError {}
+
+// The side length of the faces from the number of tiles
+pub fn face_size(board: &Board) -> Result<usize, FoldError> {
+    let tiles = board
+        .grid
+        .iter()
+        .filter(|(_, x)| !matches!(x, Tile::Void))
+        .count();
+    let size = (1..).take_while(|x| 6 * x * x <= tiles).last().unwrap_or(0);
+    if size == 0 || 6 * size * size != tiles {
+        return Err(FoldError::Size { tiles });
+    }
+    Ok(size)
+}
+
+fn neg(v: Vec3) -> Vec3 {
+    [-v[0], -v[1], -v[2]]
+}
+
+fn add(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+// A face of the net and where its board directions point on the cube.
+// The normal points out of the cube
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    cell: (usize, usize),
+    normal: Vec3,
+    right: Vec3,
+    down: Vec3,
+}
+
+impl Face {
+    // The direction on the cube of a direction on the board
+    fn direction(&self, shift: Shift) -> Vec3 {
+        let scale = |v: Vec3, k: isize| v.map(|x| x * k as i32);
+        add(scale(self.right, shift.0), scale(self.down, shift.1))
+    }
+
+    // The face across one side, folded away from the viewer
+    fn fold(&self, side: Shift, cell: (usize, usize)) -> Face {
+        let (normal, right, down) = match side {
+            (1, 0) => (self.right, neg(self.normal), self.down),
+            (-1, 0) => (neg(self.right), self.normal, self.down),
+            (0, 1) => (self.down, self.right, neg(self.normal)),
+            _ => (neg(self.down), self.right, self.normal),
+        };
+        Face {
+            cell,
+            normal,
+            right,
+            down,
+        }
+    }
+
+    // The tiles of a side as an edge that runs right or down, its normal
+    // is fixed once both edges of a pair are known
+    fn edge(&self, side: Shift, size: usize) -> Edge {
+        let (x0, y0) = corner_tile(self.cell, size);
+        let last = size - 1;
+        let (a, n) = match side {
+            (1, 0) => ((x0 + last, y0), (0, 1)),
+            (-1, 0) => ((x0, y0), (0, 1)),
+            (0, 1) => ((x0, y0 + last), (1, 0)),
+            _ => ((x0, y0), (1, 0)),
+        };
+        Edge {
+            size,
+            a,
+            n,
+            clockwise: false,
+        }
+    }
+
+    // The cube corner at the start of an edge on a side
+    fn corner(&self, side: Shift, edge: &Edge) -> Vec3 {
+        let along = self.direction(edge.n);
+        add(add(self.normal, self.direction(side)), neg(along))
+    }
+}
+
+// The top left tile of a face
+fn corner_tile(cell: (usize, usize), size: usize) -> Point {
+    (1 + cell.0 * size, 1 + cell.1 * size)
+}
+
+fn offset(cell: (usize, usize), side: Shift) -> (isize, isize) {
+    (cell.0 as isize + side.0, cell.1 as isize + side.1)
+}
+
+// The six squares of the net in board order
+fn find_faces(board: &Board, size: usize) -> Result<Vec<(usize, usize)>, FoldError> {
+    let mut cells = vec![];
+    for cy in 0..(board.height() - 1).div_ceil(size) {
+        for cx in 0..(board.width() - 1).div_ceil(size) {
+            let tiles = (0..size * size).map(|i| {
+                let point = (1 + cx * size + i % size, 1 + cy * size + i / size);
+                (point, !matches!(board.tile_at(point), Tile::Void))
+            });
+            let tiles: Vec<(Point, bool)> = tiles.collect();
+            match tiles.iter().filter(|(_, x)| *x).count() {
+                0 => {}
+                n if n == size * size => cells.push((cx, cy)),
+                _ => {
+                    return Err(FoldError::PartialFace(
+                        tiles.iter().find(|x| !x.1).unwrap().0,
+                    ))
+                }
+            }
+        }
+    }
+    if cells.len() != 6 {
+        return Err(FoldError::FaceCount(cells.len()));
+    }
+    Ok(cells)
+}
+
+// Folds the faces around the first one and glues every side that has
+// no neighbour on the board to the side it meets on the cube. The edges
+// of a pair start at the same cube corner, each one leads into its face
+pub fn fold(board: &Board, size: usize) -> Result<Vec<(Edge, Edge)>, FoldError> {
+    let cells = find_faces(board, size)?;
+    let mut faces: Vec<Option<Face>> = vec![None; cells.len()];
+    faces[0] = Some(Face {
+        cell: cells[0],
+        normal: [0, 0, -1],
+        right: [1, 0, 0],
+        down: [0, 1, 0],
+    });
+    let mut queue = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        let face = faces[i].unwrap();
+        for side in SIDES {
+            let next = offset(face.cell, side);
+            let Some(j) = cells.iter().position(|c| offset(*c, (0, 0)) == next) else {
+                continue;
+            };
+            if faces[j].is_none() {
+                faces[j] = Some(face.fold(side, cells[j]));
+                queue.push_back(j);
+            }
+        }
+    }
+    let mut folded: Vec<Face> = vec![];
+    for (cell, face) in cells.iter().zip(&faces) {
+        let Some(face) = face else {
+            return Err(FoldError::Disconnected(corner_tile(*cell, size)));
+        };
+        if let Some(other) = folded.iter().find(|x| x.normal == face.normal) {
+            return Err(FoldError::Overlap(
+                corner_tile(other.cell, size),
+                corner_tile(face.cell, size),
+            ));
+        }
+        folded.push(*face);
+    }
+
+    let mut pairs = vec![];
+    for (i, face) in folded.iter().enumerate() {
+        for side in SIDES {
+            let across = face.direction(side);
+            let j = folded.iter().position(|x| x.normal == across).unwrap();
+            let other = &folded[j];
+            let other_side = *SIDES
+                .iter()
+                .find(|x| other.direction(**x) == face.normal)
+                .unwrap();
+            let neighbours = offset(face.cell, side) == offset(other.cell, (0, 0));
+            // every pair once, sides that touch on the board need no teleport
+            if neighbours || (j, other_side) < (i, side) {
+                continue;
+            }
+            let edge = face.edge(side, size);
+            let mut other_edge = other.edge(other_side, size);
+            if face.corner(side, &edge) != other.corner(other_side, &other_edge) {
+                other_edge = Edge {
+                    a: other_edge.end(),
+                    n: (-other_edge.n.0, -other_edge.n.1),
+                    ..other_edge
+                };
+            }
+            pairs.push((inward(edge, side), inward(other_edge, other_side)));
+        }
+    }
+    Ok(pairs)
+}
+
+// Turns the edge so a teleport to it leads away from the side
+fn inward(edge: Edge, side: Shift) -> Edge {
+    let into_face = (-side.0, -side.1);
+    Edge {
+        clockwise: (edge.n.1, -edge.n.0) != into_face,
+        ..edge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cube::{face_size, fold, FoldError, SIDES},
+        read_input, Board, Cube, Solver,
+    };
+
+    // A board with faces of two tiles from a net drawn with one character per face
+    fn board(net: &[&str]) -> Board {
+        let mut text = String::new();
+        for row in net {
+            let line: String = row
+                .chars()
+                .flat_map(|c| [if c == '#' { '.' } else { ' ' }; 2])
+                .collect();
+            text += &format!("{}\n{}\n", line, line);
+        }
+        read_input(&(text + "\n1")).unwrap().0
+    }
+
+    // Every tile on a glued side teleports across and back
+    fn check_round_trips(net: &[&str]) {
+        let board = board(net);
+        let size = face_size(&board).unwrap();
+        let edges = fold(&board, size).unwrap();
+        assert_eq!(edges.len(), 7, "{:?}", net);
+        let cube = Cube {
+            board: &board,
+            cube: &edges,
+        };
+        for (a, b) in &edges {
+            for (from, to) in [(a, b), (b, a)] {
+                for i in 0..size as isize {
+                    let start = from.get_absolute(i);
+                    let inward = from.get_teleportation_shift();
+                    let outward = (-inward.0, -inward.1);
+                    assert!(SIDES.contains(&outward));
+                    let (point, shift) = cube.teleport(start, outward).unwrap();
+                    assert_eq!(point, to.get_absolute(i), "{:?} {:?}", net, start);
+                    assert_eq!(shift, to.get_teleportation_shift());
+                    let back = cube.teleport(point, (-shift.0, -shift.1)).unwrap();
+                    assert_eq!(back, (start, inward), "{:?} {:?}", net, start);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn all_eleven_nets() {
+        let nets: [&[&str]; 11] = [
+            // one row of four with a face above and below
+            &["#   ", "####", "#   "],
+            &["#   ", "####", " #  "],
+            &["#   ", "####", "  # "],
+            &["#   ", "####", "   #"],
+            &[" #  ", "####", " #  "],
+            &[" #  ", "####", "  # "],
+            // two, three and one
+            &["##  ", " ###", " #  "],
+            &["##  ", " ###", "  # "],
+            &["##  ", " ###", "   #"],
+            &["##  ", " ## ", "  ##"],
+            &["###  ", "  ###"],
+        ];
+        for net in nets {
+            check_round_trips(net);
+        }
+    }
+
+    #[test]
+    fn the_example_net() {
+        // faces of four tiles
+        let (board, _) = read_input(include_str!("../test.txt")).unwrap();
+        assert_eq!(face_size(&board), Ok(4));
+        let edges = fold(&board, 4).unwrap();
+        let cube = Cube {
+            board: &board,
+            cube: &edges,
+        };
+        // A to B in the puzzle: right off face 4 comes down into face 6
+        assert_eq!(cube.teleport((12, 6), (1, 0)), Some(((15, 9), (0, 1))));
+        // C to D: down off face 5 comes up into face 2
+        assert_eq!(cube.teleport((11, 12), (0, 1)), Some(((2, 8), (0, -1))));
+    }
+
+    #[test]
+    fn not_a_cube() {
+        let rectangle = board(&["###", "###"]);
+        assert_eq!(
+            fold(&rectangle, 2).unwrap_err(),
+            FoldError::Overlap((1, 3), (3, 3))
+        );
+        assert_eq!(
+            fold(&board(&["#####"]), 2).unwrap_err(),
+            FoldError::FaceCount(5)
+        );
+        assert_eq!(
+            fold(&board(&["###", "   ", "###"]), 2).unwrap_err(),
+            FoldError::Disconnected((1, 5))
+        );
+        assert_eq!(
+            fold(&board(&["# ##", "####"]), 3).unwrap_err(),
+            FoldError::PartialFace((3, 1))
+        );
+        assert_eq!(
+            face_size(&board(&["###"])),
+            Err(FoldError::Size { tiles: 12 })
+        );
+    }
+}
@@ -1,235 +1,10 @@
+use aoc_common::{numbered_lines, Answer, Cursor, Grid, ParseError, ParseResult, Solution, Vector};
 use std::{
+    any::Any,
     cmp::{max, min},
-    io,
 };
 
-// X: 1 -> 50; 51 -> 100; 101 -> 150
-// Y: 1 -> 50; 51 -> 100; 101 -> 150; 151 -> 200
-// unique markers:
-// |||
-// X
-// ><
-// O
-// []
-// |
-// --
-#[allow(dead_code)]
-const CUBE_TEST: [(Edge, Edge); 7] = [
-    // A -> B
-    (
-        Edge {
-            size: 4,
-            a: (12, 5),
-            n: (0, 1), // down
-            clockwise: false,
-        },
-        Edge {
-            size: 4,
-            a: (16, 9),
-            n: (-1, 0), // left
-            clockwise: false,
-        },
-    ),
-    // C -> D
-    (
-        Edge {
-            size: 4,
-            a: (13, 12),
-            n: (1, 0), // right
-            clockwise: false,
-        },
-        Edge {
-            size: 4,
-            a: (1, 5),
-            n: (0, 1), // down
-            clockwise: false,
-        },
-    ),
-    // E -> F
-    (
-        Edge {
-            size: 4,
-            a: (1, 8),
-            n: (1, 0), // right
-            clockwise: false,
-        },
-        Edge {
-            size: 4,
-            a: (12, 12),
-            n: (-1, 0), // left
-            clockwise: false,
-        },
-    ),
-    // G -> H
-    (
-        Edge {
-            size: 4,
-            a: (5, 5),
-            n: (1, 0), // right
-            clockwise: false,
-        },
-        Edge {
-            size: 4,
-            a: (9, 1),
-            n: (0, 1), // down
-            clockwise: false,
-        },
-    ),
-    // ? -> ?
-    (
-        Edge {
-            size: 1,
-            a: (0, 0),
-            n: (0, 0),
-            clockwise: false,
-        },
-        Edge {
-            size: 1,
-            a: (0, 0),
-            n: (0, 0),
-            clockwise: false,
-        },
-    ),
-    // ? -> ?
-    (
-        Edge {
-            size: 1,
-            a: (0, 0),
-            n: (0, 0),
-            clockwise: false,
-        },
-        Edge {
-            size: 1,
-            a: (0, 0),
-            n: (0, 0),
-            clockwise: false,
-        },
-    ),
-    // ? -> ?
-    (
-        Edge {
-            size: 1,
-            a: (0, 0),
-            n: (0, 0),
-            clockwise: false,
-        },
-        Edge {
-            size: 1,
-            a: (0, 0),
-            n: (0, 0),
-            clockwise: false,
-        },
-    ),
-];
-
-#[allow(dead_code)]
-const CUBE: [(Edge, Edge); 7] = [
-    // A
-    (
-        Edge {
-            size: 50,
-            a: (100, 100),
-            n: (0, -1), // up
-            clockwise: false,
-        },
-        Edge {
-            size: 50,
-            a: (150, 50),
-            n: (-1, 0), // left
-            clockwise: true,
-        },
-    ),
-    // B
-    (
-        Edge {
-            size: 50,
-            a: (51, 51),
-            n: (0, 1), // down
-            clockwise: false,
-        },
-        Edge {
-            size: 50,
-            a: (1, 101),
-            n: (1, 0), // right
-            clockwise: true,
-        },
-    ),
-    // C
-    (
-        Edge {
-            size: 50,
-            a: (100, 150),
-            n: (-1, 0), // left
-            clockwise: true,
-        },
-        Edge {
-            size: 50,
-            a: (50, 200),
-            n: (0, -1), // up
-            clockwise: false,
-        },
-    ),
-    // D
-    (
-        Edge {
-            size: 50,
-            a: (51, 1),
-            n: (0, 1), // down
-            clockwise: false,
-        },
-        Edge {
-            size: 50,
-            a: (1, 150),
-            n: (0, -1), // up
-            clockwise: true,
-        },
-    ),
-    // E
-    (
-        Edge {
-            size: 50,
-            a: (100, 1),
-            n: (-1, 0), // left
-            clockwise: false,
-        },
-        Edge {
-            size: 50,
-            a: (1, 200),
-            n: (0, -1), // up
-            clockwise: true,
-        },
-    ),
-    // F
-    (
-        Edge {
-            size: 50,
-            a: (150, 1),
-            n: (-1, 0), // left
-            clockwise: false,
-        },
-        Edge {
-            size: 50,
-            a: (50, 200),
-            n: (-1, 0), // left
-            clockwise: true,
-        },
-    ),
-    // G
-    (
-        Edge {
-            size: 50,
-            a: (150, 1),
-            n: (0, 1), // down
-            clockwise: true,
-        },
-        Edge {
-            size: 50,
-            a: (100, 150),
-            n: (0, -1), // up
-            clockwise: false,
-        },
-    ),
-];
+mod cube;
 
 type Point = (usize, usize);
 type Shift = (isize, isize);
@@ -250,49 +25,31 @@ enum Move {
 
 #[derive(Debug)]
 struct Board {
-    grid: [[Tile; 151]; 201], // 150 + 1 columns; 200 + 1 rows; +1 cause 0 is Void
-                              // to prevent negative indicies
+    // row and column 0 are Void to prevent negative indices,
+    // the board starts at 1 like the puzzle counts
+    grid: Grid<Tile>,
 }
 
 impl Board {
-    fn new() -> Board {
-        Board {
-            grid: [[Tile::Void; 151]; 201],
-        }
-    }
-
     fn width(&self) -> usize {
-        self.grid[0].len()
+        self.grid.width()
     }
 
     fn height(&self) -> usize {
-        self.grid.len()
+        self.grid.height()
     }
 
     fn get_start(&self) -> Point {
-        for y in 0..self.height() {
-            for x in 0..self.width() {
-                let tile = self.grid[y][x];
-                match tile {
-                    Tile::Open => {
-                        return (x, y);
-                    }
-                    _ => continue,
-                }
-            }
+        match self.grid.find(|tile| matches!(tile, Tile::Open)) {
+            Some(start) => (start.x as usize, start.y as usize),
+            None => (0, 0),
         }
-        (0, 0)
     }
 
     fn tile_at(&self, position: Point) -> Tile {
         let (x, y) = position;
-        if y == 0 || x == 0 {
-            return Tile::Void;
-        }
-        if y > self.grid.len() - 1 || x > self.width() - 1 {
-            return Tile::Void;
-        }
-        self.grid[y][x]
+        let tile = self.grid.get(Vector::new(x as isize, y as isize));
+        tile.copied().unwrap_or(Tile::Void)
     }
 }
 
@@ -393,7 +150,7 @@ impl<'a> Solver for Flat<'a> {
 
 struct Cube<'a> {
     board: &'a Board,
-    cube: &'a [(Edge, Edge); 7],
+    cube: &'a [(Edge, Edge)],
 }
 
 impl<'a> Solver for Cube<'a> {
@@ -423,7 +180,7 @@ impl<'a> Solver for Cube<'a> {
         }
 
         let relative = edge_from.get_relative(position);
-        let next_position = edge_to.from_relative(relative);
+        let next_position = edge_to.get_absolute(relative);
         let next_shift = edge_to.get_teleportation_shift();
 
         // Check if teleportation is blocked by obstacle
@@ -501,7 +258,7 @@ impl Edge {
         }
     }
 
-    fn from_relative(&self, relative: isize) -> Point {
+    fn get_absolute(&self, relative: isize) -> Point {
         let (ax, ay) = self.a;
         let (nx, ny) = self.n;
         if self.is_vertical() {
@@ -520,59 +277,49 @@ impl Edge {
     }
 }
 
-fn parse_path(path: String) -> Vec<Move> {
-    let mut moves = Vec::new();
-    let mut buff = Vec::new();
-
-    for c in path.chars() {
-        match c {
-            'L' => {
-                let n = buff.iter().collect::<String>().parse::<usize>().unwrap();
-                moves.push(Move::Straight(n));
-                buff = Vec::new();
-                moves.push(Move::Left);
-            }
-            'R' => {
-                let n = buff.iter().collect::<String>().parse::<usize>().unwrap();
-                moves.push(Move::Straight(n));
-                buff = Vec::new();
-                moves.push(Move::Right);
-            }
-            x => {
-                buff.push(x);
-            }
-        }
+fn parse_path(number: usize, path: &str) -> ParseResult<Vec<Move>> {
+    let mut cursor = Cursor::new(number, path);
+    let mut moves = vec![Move::Straight(cursor.number::<usize>()?)];
+    while !cursor.is_end() {
+        let turns = [("L", Move::Left), ("R", Move::Right)];
+        moves.push(cursor.one_of("\"L\" or \"R\"", &turns)?);
+        moves.push(Move::Straight(cursor.number::<usize>()?));
     }
-
-    let n = buff.iter().collect::<String>().parse::<usize>().unwrap();
-    moves.push(Move::Straight(n));
-
-    moves
+    Ok(moves)
 }
 
-fn read_input() -> (Board, Vec<Move>) {
-    let mut board = Board::new();
-    for (y, line) in io::stdin().lines().enumerate() {
-        let line = line.unwrap();
+fn read_input(input: &str) -> ParseResult<(Board, Vec<Move>)> {
+    let mut lines = numbered_lines(input);
+    let mut rows = vec![];
+    for (y, line) in lines.by_ref() {
         if line.is_empty() {
             break;
         }
 
-        for (x, t) in line.chars().enumerate() {
-            let tile = match t {
-                '.' => Tile::Open,
-                '#' => Tile::Solid,
-                ' ' => Tile::Void,
-                _ => panic!("unknown tile"),
-            };
-            board.grid[y + 1][x + 1] = tile; // +1 cause 0 is Void
+        let mut cursor = Cursor::new(y, line);
+        let tiles = [(".", Tile::Open), ("#", Tile::Solid), (" ", Tile::Void)];
+        let mut row = vec![];
+        while !cursor.is_end() {
+            row.push(cursor.one_of("a tile", &tiles)?);
         }
+        rows.push(row);
     }
-
-    let path = io::stdin().lines().next().unwrap().unwrap();
-    let moves = parse_path(path);
-
-    (board, moves)
+    // rows and columns start at 1 like the puzzle, 0 is Void
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let grid = Grid::from_fn(width + 1, rows.len() + 1, |p| {
+        let row = (p.y as usize).checked_sub(1).and_then(|y| rows.get(y));
+        let tile = (p.x as usize).checked_sub(1).and_then(|x| row?.get(x));
+        tile.copied().unwrap_or(Tile::Void)
+    });
+    let board = Board { grid };
+
+    let last = input.lines().count();
+    let (number, path) = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(last + 1, "a path"))?;
+    let moves = parse_path(number, path)?;
+
+    Ok((board, moves))
 }
 
 fn add(position: Point, shift: Shift) -> Point {
@@ -581,7 +328,8 @@ fn add(position: Point, shift: Shift) -> Point {
     ((x as isize + sx) as usize, (y as isize + sy) as usize)
 }
 
-fn print_path(board: &Board, path: &Vec<(Point, Shift)>, max_x: usize, max_y: usize) {
+#[allow(dead_code)]
+fn print_path(board: &Board, path: &[(Point, Shift)], max_x: usize, max_y: usize) {
     for y in 1..max_y {
         for x in 1..max_x {
             let pos = (x, y);
@@ -608,11 +356,11 @@ fn print_path(board: &Board, path: &Vec<(Point, Shift)>, max_x: usize, max_y: us
             };
             print!("{}", c);
         }
-        println!("");
+        println!();
     }
 }
 
-fn part_one(board: &Board, path: &Vec<Move>) -> usize {
+fn part_one(board: &Board, path: &[Move]) -> usize {
     let start = board.get_start();
     let shift: Shift = (1, 0);
 
@@ -622,14 +370,23 @@ fn part_one(board: &Board, path: &Vec<Move>) -> usize {
     result
 }
 
-fn part_two(board: &Board, path: &Vec<Move>) -> usize {
+fn part_two(board: &Board, path: &[Move]) -> ParseResult<usize> {
     let start = board.get_start();
     let shift: Shift = (1, 0);
 
-    let solver = Cube { board, cube: &CUBE };
-    // let solver = Cube { board, cube: &CUBE_TEST };
+    // a net that doesn't fold points at one of its tiles
+    let edges = cube::face_size(board)
+        .and_then(|size| cube::fold(board, size))
+        .map_err(|error| {
+            let (x, y) = error.position();
+            ParseError::new(y, x, "a cube net", &error.to_string())
+        })?;
+    let solver = Cube {
+        board,
+        cube: &edges,
+    };
 
-    let (result, log) = solve(&solver, start, shift, path);
+    let (result, _log) = solve(&solver, start, shift, path);
 
     // debug
     // print_path(board, &log, 17, 13);
@@ -641,14 +398,14 @@ fn part_two(board: &Board, path: &Vec<Move>) -> usize {
     //     println!("{} {} ({:?})", x, y, shift);
     // }
 
-    result
+    Ok(result)
 }
 
 fn solve<S: Solver>(
     solver: &S,
     start_postion: Point,
     start_shift: Shift,
-    moves: &Vec<Move>,
+    moves: &[Move],
 ) -> (usize, Vec<(Point, Shift)>) {
     // 0. take start
     let mut position = start_postion;
@@ -724,14 +481,22 @@ fn solve<S: Solver>(
     (result, log)
 }
 
-fn main() {
-    let (board, path) = read_input();
+pub struct Day22;
 
-    let result = part_one(&board, &path);
-    println!("Part one: {}", result);
+impl Solution for Day22 {
+    fn parse(&self, input: &str) -> Option<ParseResult<Box<dyn Any>>> {
+        Some(read_input(input).map(|x| Box::new(x) as Box<dyn Any>))
+    }
+
+    fn part_one(&self, input: &str) -> ParseResult<Answer> {
+        let (board, path) = read_input(input)?;
+        Ok(part_one(&board, &path).into())
+    }
 
-    let result = part_two(&board, &path);
-    println!("Part two: {}", result);
+    fn part_two(&self, input: &str) -> ParseResult<Answer> {
+        let (board, path) = read_input(input)?;
+        Ok(part_two(&board, &path)?.into())
+    }
 }
 
 #[cfg(test)]
@@ -765,8 +530,8 @@ mod test {
             clockwise: false,
         };
 
-        assert_eq!(e.from_relative(0), (100, 100));
-        assert_eq!(e.from_relative(20), (100, 80));
-        assert_eq!(e.from_relative(49), (100, 51));
+        assert_eq!(e.get_absolute(0), (100, 100));
+        assert_eq!(e.get_absolute(20), (100, 80));
+        assert_eq!(e.get_absolute(49), (100, 51));
     }
 }
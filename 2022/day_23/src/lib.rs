@@ -0,0 +1,237 @@
+use aoc_common::{
+    grid::{DOWN, LEFT, RIGHT, UP},
+    Answer, Cells, ParseError, ParseResult, Solution, SparseGrid, Vector,
+};
+use std::{
+    any::Any,
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+#[derive(Debug, Clone, Copy)]
+struct Elf;
+
+impl Display for Elf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#")
+    }
+}
+
+const N_MOVE: Vector = UP;
+const S_MOVE: Vector = DOWN;
+const W_MOVE: Vector = LEFT;
+const E_MOVE: Vector = RIGHT;
+
+const NE_MOVE: Vector = Vector { x: 1, y: -1 };
+const NW_MOVE: Vector = Vector { x: -1, y: -1 };
+const SE_MOVE: Vector = Vector { x: 1, y: 1 };
+const SW_MOVE: Vector = Vector { x: -1, y: 1 };
+
+const START_ORDER: [(Vector, (Vector, Vector, Vector)); 4] = [
+    (N_MOVE, (NW_MOVE, N_MOVE, NE_MOVE)),
+    (S_MOVE, (SW_MOVE, S_MOVE, SE_MOVE)),
+    (W_MOVE, (NW_MOVE, W_MOVE, SW_MOVE)),
+    (E_MOVE, (NE_MOVE, E_MOVE, SE_MOVE)),
+];
+
+enum Adjacent {
+    All(Vector),
+    Line((Vector, Vector, Vector)),
+}
+
+#[derive(Debug, Clone)]
+struct Squad {
+    elves: SparseGrid<Elf>,
+    order: [(Vector, (Vector, Vector, Vector)); 4],
+}
+
+impl Squad {
+    fn new() -> Squad {
+        Squad {
+            elves: SparseGrid::new(),
+            order: START_ORDER,
+        }
+    }
+
+    fn rotate_order(&mut self) {
+        let first = self.order[0];
+        for i in 1..self.order.len() {
+            self.order[i - 1] = self.order[i];
+        }
+        let last = self.order.len() - 1;
+        self.order[last] = first;
+    }
+
+    fn count_adjacents(&self, a: Adjacent) -> usize {
+        // let mut elves: HashMap<Vector, &Elf> = HashMap::new();
+        // for elf in &self.elves {
+        //     elves.insert(elf.position, elf);
+        // }
+
+        match a {
+            Adjacent::All(position) => self.elves.neighbours8(position).count(),
+            Adjacent::Line((a, b, c)) => {
+                let positions = [a, b, c];
+                positions
+                    .iter()
+                    .filter(|pos| self.elves.contains(**pos))
+                    .count()
+            }
+        }
+    }
+
+    fn occupied(&self, position: Vector) -> bool {
+        self.elves.contains(position)
+    }
+
+    fn bounds(&self) -> (isize, isize, isize, isize) {
+        let (min, max) = self.elves.bounds().unwrap();
+        (min.y, max.y, min.x, max.x)
+    }
+}
+
+fn print_squad(squad: &Squad) {
+    println!("{}", squad.elves);
+}
+
+fn run_round(squad: &mut Squad) -> usize {
+    let mut moves = 0;
+
+    // Start of first part
+    let mut proposes: HashMap<Vector, Vector> = HashMap::new();
+    for elf in squad.elves.positions() {
+        let count = squad.count_adjacents(Adjacent::All(elf));
+
+        // Do nothing with the elf if he is alone
+        if count == 0 {
+            continue;
+        }
+
+        // Find proposed move for the elf
+        for (step, line) in squad.order {
+            let line = (elf + line.0, elf + line.1, elf + line.2);
+            let count = squad.count_adjacents(Adjacent::Line(line));
+            if count == 0 {
+                let propose = elf + step;
+                proposes.insert(elf, propose);
+                break;
+            }
+        }
+    }
+
+    // Count how many elves are going to occupy propose
+    let mut propose_counts: HashMap<Vector, usize> = HashMap::new();
+    for propose in proposes.values() {
+        if let Some(count) = propose_counts.get(propose) {
+            propose_counts.insert(*propose, count + 1);
+        } else {
+            propose_counts.insert(*propose, 1);
+        }
+    }
+
+    // Second part
+    let mut new_elves = SparseGrid::new();
+    for elf in squad.elves.positions() {
+        if let Some(propose) = proposes.get(&elf) {
+            if let Some(count) = propose_counts.get(propose) {
+                if *count == 1 {
+                    new_elves.insert(*propose, Elf);
+                    moves += 1;
+                } else {
+                    new_elves.insert(elf, Elf);
+                }
+            }
+        } else {
+            new_elves.insert(elf, Elf);
+        }
+    }
+
+    squad.elves = new_elves;
+    squad.rotate_order();
+
+    moves
+}
+
+fn part_one(mut squad: Squad, debug: bool) -> usize {
+    if debug {
+        println!("== Initial State ==");
+        print_squad(&squad);
+        println!();
+    }
+
+    for round in 1..=10 {
+        run_round(&mut squad);
+
+        if debug {
+            println!("== End of Round {} ==", round);
+            print_squad(&squad);
+            println!();
+        }
+    }
+
+    let (n, s, w, e) = squad.bounds();
+    let mut result = 0;
+    for y in n..=s {
+        for x in w..=e {
+            let cell = Vector::new(x, y);
+            if !squad.occupied(cell) {
+                result += 1;
+            }
+        }
+    }
+    result
+}
+
+fn part_two(mut squad: Squad) -> usize {
+    let mut rounds = 0;
+    loop {
+        let moves = run_round(&mut squad);
+        rounds += 1;
+        if moves == 0 {
+            break;
+        }
+    }
+    rounds
+}
+
+fn read_input(input: &str) -> ParseResult<Squad> {
+    let mut squad = Squad::new();
+    squad.elves = SparseGrid::parse(input, "\"#\" or \".\"", '.', |c| (c == '#').then_some(Elf))?;
+    // the empty ground is measured around the elves
+    if squad.elves.is_empty() {
+        let line = input.lines().count() + 1;
+        return Err(ParseError::end_of_input(line, "an elf \"#\""));
+    }
+    Ok(squad)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    fn parse(&self, input: &str) -> Option<ParseResult<Box<dyn Any>>> {
+        Some(read_input(input).map(|x| Box::new(x) as Box<dyn Any>))
+    }
+
+    fn part_one(&self, input: &str) -> ParseResult<Answer> {
+        Ok(part_one(read_input(input)?, false).into())
+    }
+
+    fn part_two(&self, input: &str) -> ParseResult<Answer> {
+        Ok(part_two(read_input(input)?).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day23;
+    use aoc_common::{ParseError, Solution};
+
+    #[test]
+    fn empty_input() {
+        let error = ParseError::end_of_input(1, "an elf \"#\"");
+        assert_eq!(Day23.part_one(""), Err(error.clone()));
+        assert_eq!(Day23.part_two(""), Err(error));
+        let error = ParseError::end_of_input(3, "an elf \"#\"");
+        assert_eq!(Day23.part_one("...\n..."), Err(error));
+    }
+}
@@ -1,14 +1,14 @@
+use aoc_common::{graph::astar, Answer, Grid, ParseResult, Solution};
 use std::{
-    collections::{BinaryHeap, HashMap, HashSet},
-    io, thread,
+    any::Any,
+    collections::{HashMap, HashSet},
+    thread,
     time::Duration,
 };
 
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 struct Vector(isize, isize);
 
-type Grid<T> = Vec<Vec<T>>;
-
 const U: Vector = Vector(0, -1);
 const D: Vector = Vector(0, 1);
 const L: Vector = Vector(-1, 0);
@@ -34,6 +34,7 @@ fn lcm(first: usize, second: usize) -> usize {
     first * second / x
 }
 
+#[allow(dead_code)]
 #[derive(Debug)]
 enum Tile {
     Void,
@@ -47,6 +48,7 @@ impl Vector {
         Vector(self.0 + other.0, self.1 + other.1)
     }
 
+    #[allow(dead_code)]
     fn sub(&self, other: &Vector) -> Vector {
         Vector(self.0 - other.0, self.1 - other.1)
     }
@@ -64,6 +66,7 @@ impl Vector {
         (x >= xmin && x <= xmax) && (y >= ymin && y <= ymax)
     }
 
+    #[allow(dead_code)]
     fn get_move(&self, other: &Vector) -> char {
         match other.sub(self) {
             Vector(1, 0) => 'R',
@@ -76,53 +79,6 @@ impl Vector {
     }
 }
 
-#[derive(Debug, Hash, Clone, PartialEq, Eq)]
-struct Squad {
-    ts: usize,
-    trace: Vec<Vector>,
-}
-
-impl Ord for Squad {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.ts.cmp(&other.ts).reverse()
-    }
-}
-
-impl PartialOrd for Squad {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Squad {
-    fn apply(&self, pos: Vector) -> Squad {
-        let mut trace = self.trace.clone();
-        trace.push(pos);
-        Squad {
-            ts: self.next(),
-            trace,
-        }
-    }
-
-    fn position(&self) -> &Vector {
-        self.trace.last().unwrap()
-    }
-
-    fn next(&self) -> usize {
-        self.ts + 1
-    }
-
-    fn get_trace_value(&self) -> String {
-        let s = self.trace.len();
-        let head = self.trace.iter().take(s - 1);
-        let tail = self.trace.iter().skip(1);
-        head.zip(tail)
-            .map(|(a, b)| a.get_move(b))
-            .into_iter()
-            .collect()
-    }
-}
-
 #[derive(Debug, Clone)]
 struct Blizzard {
     pos: Vector,
@@ -188,6 +144,7 @@ impl Valley {
         self.stat.insert(self.ts, blizzards);
     }
 
+    #[allow(dead_code)]
     fn get_blizzard_map(&self) -> HashMap<Vector, Tile> {
         self.blizzards.iter().fold(HashMap::new(), |mut acc, b| {
             match acc.get(&b.pos) {
@@ -206,9 +163,9 @@ impl Valley {
 
     fn get_playground_bounds(&self) -> (usize, usize, usize, usize) {
         let xmin = 1;
-        let xmax = self.grid[0].len() - 2;
+        let xmax = self.grid.width() - 2;
         let ymin = 1;
-        let ymax = self.grid.len() - 2;
+        let ymax = self.grid.height() - 2;
         (xmin, xmax, ymin, ymax)
     }
 
@@ -234,67 +191,44 @@ impl Valley {
     }
 }
 
-fn read_input() -> Valley {
+fn read_input(input: &str) -> ParseResult<Valley> {
+    let expected = "a wall, ground or a blizzard";
+    let map = Grid::parse(input, expected, |c| "#.><v^".contains(c).then_some(c))?;
+    let last_row = map.height() as isize - 1;
     let mut start = Vector(0, 0);
     let mut finish = Vector(0, 0);
     let mut blizzards = Vec::new();
-    let mut grid = Vec::new();
-
-    let lines: Vec<String> = io::stdin().lines().map(|line| line.unwrap()).collect();
-    for (y, line) in lines.iter().enumerate() {
-        let first_row = y == 0;
-        let last_row = y == lines.len() - 1;
-        let chars: Vec<char> = line.chars().collect();
-        grid.push(Vec::new());
-        for (x, cell) in chars.iter().enumerate() {
-            let pos = Vector(x as isize, y as isize);
-            let is_wall = match cell {
-                '#' => true,
-                _ => {
-                    let first_col = x == 0;
-                    let last_col = x == chars.len() - 1;
-                    first_row | last_row | first_col | last_col
-                }
-            };
-            match cell {
-                '.' => {
-                    if is_wall {
-                        if first_row {
-                            start = pos.clone();
-                        } else if last_row {
-                            finish = pos.clone();
-                        }
-                    }
-                }
-                _ => (),
-            };
-            match cell {
-                '>' => blizzards.push(Blizzard { pos, dir: R }),
-                '<' => blizzards.push(Blizzard { pos, dir: L }),
-                'v' => blizzards.push(Blizzard { pos, dir: D }),
-                '^' => blizzards.push(Blizzard { pos, dir: U }),
-                _ => (),
-            };
-            let tile = match cell {
-                '#' => Tile::Wall,
-                _ => Tile::Void,
-            };
-            grid[y].push(tile);
-        }
-    }
-    Valley {
+    for (position, cell) in map.iter() {
+        let pos = Vector(position.x, position.y);
+        match cell {
+            // the gaps in the top and bottom wall
+            '.' if pos.1 == 0 => start = pos,
+            '.' if pos.1 == last_row => finish = pos,
+            '>' => blizzards.push(Blizzard { pos, dir: R }),
+            '<' => blizzards.push(Blizzard { pos, dir: L }),
+            'v' => blizzards.push(Blizzard { pos, dir: D }),
+            '^' => blizzards.push(Blizzard { pos, dir: U }),
+            _ => (),
+        };
+    }
+    let grid = map.map(|cell| match cell {
+        '#' => Tile::Wall,
+        _ => Tile::Void,
+    });
+    Ok(Valley {
         ts: 0,
         grid,
         blizzards,
         start,
         finish,
         stat: HashMap::new(),
-    }
+    })
 }
 
+#[allow(dead_code)]
 fn print_valley(valley: &Valley, e: Option<Vector>) {
     let blizzards = valley.get_blizzard_map();
-    for (y, row) in valley.grid.iter().enumerate() {
+    for (y, row) in valley.grid.rows().enumerate() {
         for (x, tile) in row.iter().enumerate() {
             let mut c = match tile {
                 Tile::Void => '.',
@@ -332,90 +266,69 @@ fn print_valley(valley: &Valley, e: Option<Vector>) {
             }
             print!("{}", c);
         }
-        println!("");
+        println!();
     }
 }
 
-fn solve(valley: &Valley, start: Vector, finish: Vector, ts: usize) -> Option<Squad> {
-    let squad = Squad {
-        ts,
-        trace: vec![start],
-    };
-
-    let mut seen = HashSet::new();
-    let mut queue = BinaryHeap::new();
-
-    seen.insert((*squad.position(), squad.ts % valley.period()));
-    queue.push(squad);
-
-    while let Some(squad) = queue.pop() {
-        let pos = squad.position();
-
-        // Done
-        if pos == &finish {
-            return Some(squad);
-        }
-
-        let new_ts = squad.next();
+// A* over the position and the time of arrival, waiting in place is a step as well
+fn solve(valley: &Valley, start: Vector, finish: Vector, ts: usize) -> Option<usize> {
+    let neighbours = |&(pos, ts): &(Vector, usize)| {
+        let new_ts = ts + 1;
         STEPS
             .iter()
-            .map(|step| pos.add(step))
+            .map(move |step| pos.add(step))
             .filter(|pos| !valley.is_wall(pos))
-            .filter(|pos| !valley.is_blizzard(pos, new_ts))
-            .for_each(|pos| {
-                let new_squad = squad.apply(pos);
-                if seen.insert((*new_squad.position(), new_squad.ts % valley.period())) {
-                    queue.push(new_squad);
-                }
-            });
-    }
-    None
+            .filter(move |pos| !valley.is_blizzard(pos, new_ts))
+            .map(move |pos| ((pos, new_ts), 1))
+    };
+    let distance = |(pos, _): &(Vector, usize)| pos.0.abs_diff(finish.0) + pos.1.abs_diff(finish.1);
+    let path = astar((start, ts), neighbours, distance, |(pos, _)| *pos == finish)?;
+    Some(ts + path.cost)
 }
 
 fn part_one(valley: &Valley) -> usize {
     let start = valley.start;
     let finish = valley.finish;
-    if let Some(squad) = solve(valley, start, finish, 0) {
-        return squad.ts;
-    }
-
-    0
+    solve(valley, start, finish, 0).unwrap_or(0)
 }
 
 fn part_two(valley: &Valley) -> usize {
     let start = valley.start;
     let finish = valley.finish;
     let mut ts = 0;
-    if let Some(squad) = solve(valley, start, finish, ts) {
-        ts = squad.ts;
+    if let Some(arrival) = solve(valley, start, finish, ts) {
+        ts = arrival;
     }
 
-    if let Some(squad) = solve(valley, finish, start, ts) {
-        ts = squad.ts;
+    if let Some(arrival) = solve(valley, finish, start, ts) {
+        ts = arrival;
     }
 
-    if let Some(squad) = solve(valley, start, finish, ts) {
-        ts = squad.ts;
+    if let Some(arrival) = solve(valley, start, finish, ts) {
+        ts = arrival;
     }
 
     ts
 }
 
+#[allow(dead_code)]
 fn simulate(valley: &mut Valley) {
     loop {
-        print_valley(&valley, None);
+        print_valley(valley, None);
         valley.tick();
         thread::sleep(Duration::from_millis(100));
         clear();
     }
 }
 
+#[allow(dead_code)]
 fn clear() {
     print!("{}[2J", 27 as char);
 }
 
-fn main() {
-    let mut valley = read_input();
+// blizzards repeat after a period, so all of their positions are precomputed once
+fn prepare_valley(input: &str) -> ParseResult<Valley> {
+    let mut valley = read_input(input)?;
     valley.save_blizzard_positions();
     for _ in 0..valley.period() {
         valley.tick();
@@ -424,11 +337,23 @@ fn main() {
 
     // simulate(&mut valley);
 
-    let result = part_one(&valley);
-    println!("Part one: {}", result);
+    Ok(valley)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn parse(&self, input: &str) -> Option<ParseResult<Box<dyn Any>>> {
+        Some(prepare_valley(input).map(|x| Box::new(x) as Box<dyn Any>))
+    }
+
+    fn part_one(&self, input: &str) -> ParseResult<Answer> {
+        Ok(part_one(&prepare_valley(input)?).into())
+    }
 
-    let result = part_two(&valley);
-    println!("Part two: {}", result);
+    fn part_two(&self, input: &str) -> ParseResult<Answer> {
+        Ok(part_two(&prepare_valley(input)?).into())
+    }
 }
 
 #[cfg(test)]
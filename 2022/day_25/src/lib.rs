@@ -0,0 +1,135 @@
+use aoc_common::{numbered_lines, Answer, Cursor, NumeralError, ParseError, ParseResult, Solution};
+use snafu::Snafu;
+use std::any::Any;
+
+mod snafu;
+
+fn parse_snafu(number: usize, line: &str) -> ParseResult<Snafu> {
+    match line.parse() {
+        Ok(snafu) => Ok(snafu),
+        Err(NumeralError::InvalidDigit(offset)) => {
+            // skip the valid digits to point at the bad one
+            let mut cursor = Cursor::new(number, line);
+            cursor.tag(&line[..offset])?;
+            Err(cursor.error("a SNAFU digit"))
+        }
+        Err(error) => Err(ParseError::new(
+            number,
+            1,
+            "a SNAFU number",
+            &error.to_string(),
+        )),
+    }
+}
+
+fn read_input(input: &str) -> ParseResult<Vec<Snafu>> {
+    numbered_lines(input)
+        .map(|(number, line)| parse_snafu(number, line))
+        .collect()
+}
+
+fn part_one(numbers: &[Snafu]) -> String {
+    numbers.iter().sum::<Snafu>().to_string()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn parse(&self, input: &str) -> Option<ParseResult<Box<dyn Any>>> {
+        Some(read_input(input).map(|x| Box::new(x) as Box<dyn Any>))
+    }
+
+    fn part_one(&self, input: &str) -> ParseResult<Answer> {
+        Ok(part_one(&read_input(input)?).into())
+    }
+
+    fn part_two(&self, _input: &str) -> ParseResult<Answer> {
+        // the last day has a single puzzle
+        Ok(Answer::Text(String::new()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Numeral, ParseError};
+
+    use crate::{parse_snafu, Snafu};
+
+    const TABLE: [(&str, isize); 13] = [
+        ("1=-0-2", 1747),
+        ("12111", 906),
+        ("2=0=", 198),
+        ("21", 11),
+        ("2=01", 201),
+        ("111", 31),
+        ("20012", 1257),
+        ("112", 32),
+        ("1=-1=", 353),
+        ("1-12", 107),
+        ("12", 7),
+        ("1=", 3),
+        ("122", 37),
+    ];
+
+    #[test]
+    fn test_snafu_to_decimal() {
+        for (text, value) in TABLE {
+            let snafu = text.parse::<Snafu>().unwrap();
+            assert_eq!(isize::try_from(&snafu), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_decimal_to_snafu() {
+        for (text, value) in TABLE {
+            let snafu = text.parse::<Snafu>().unwrap();
+            assert_eq!(Snafu::from(value), snafu);
+        }
+    }
+
+    #[test]
+    fn table_round_trips_through_numerals() {
+        let snafu = Numeral::balanced("=-012").unwrap();
+        let systems = [
+            Numeral::standard(10).unwrap(),
+            Numeral::standard(2).unwrap(),
+            Numeral::balanced("-0+").unwrap(),
+            Numeral::bijective("ABCDEFGHIJKLMNOPQRSTUVWXYZ").unwrap(),
+        ];
+        for (text, value) in TABLE {
+            assert_eq!(snafu.parse(text), Ok(value as i128));
+            assert_eq!(snafu.format(value as i128).as_deref(), Some(text));
+            assert_eq!(Snafu::from(value).to_string(), text);
+            for system in &systems {
+                let converted = snafu.convert(text, system).unwrap();
+                assert_eq!(system.convert(&converted, &snafu).as_deref(), Ok(text));
+            }
+        }
+        assert_eq!(snafu.convert("1=-0-2", &systems[0]).as_deref(), Ok("1747"));
+    }
+
+    #[test]
+    fn snafu_type_agrees_with_numeral() {
+        let snafu = Numeral::balanced("=-012").unwrap();
+        for value in -1000..1000 {
+            let text = Snafu::from(value).to_string();
+            assert_eq!(snafu.format(value as i128).as_deref(), Some(text.as_str()));
+            assert_eq!(
+                text.parse::<Snafu>().map(|x| isize::try_from(&x)),
+                Ok(Ok(value))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_snafu_digits() {
+        assert_eq!(
+            parse_snafu(1, "1=-0-2"),
+            Ok("1=-0-2".parse::<Snafu>().unwrap())
+        );
+        assert_eq!(
+            parse_snafu(4, "12+1"),
+            Err(ParseError::new(4, 3, "a SNAFU digit", "\"+1\""))
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use aoc_common::{Numeral, NumeralError};
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    iter::Sum,
+    ops::{Add, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+// Balanced base 5 with the digits =, -, 0, 1 and 2 for -2 to 2.
+// The digits are kept least significant first without leading zeros,
+// so zero has no digits and the numbers are as long as they need to be
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Snafu {
+    digits: Vec<i8>,
+}
+
+fn numeral() -> Numeral {
+    Numeral::balanced("=-012").expect("five symbols around zero")
+}
+
+impl Snafu {
+    pub fn zero() -> Snafu {
+        Snafu::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    // Carries the sums or products of digits into the -2..=2 range
+    fn normalize(values: impl IntoIterator<Item = i64>) -> Snafu {
+        let mut digits = vec![];
+        let mut carry = 0;
+        let mut values = values.into_iter();
+        loop {
+            let value = match values.next() {
+                Some(value) => value + carry,
+                None if carry != 0 => carry,
+                None => break,
+            };
+            let digit = (value + 2).rem_euclid(5) - 2;
+            digits.push(digit as i8);
+            carry = (value - digit) / 5;
+        }
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        Snafu { digits }
+    }
+
+    fn digit(&self, index: usize) -> i64 {
+        self.digits.get(index).copied().unwrap_or(0) as i64
+    }
+}
+
+impl FromStr for Snafu {
+    type Err = NumeralError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.is_empty() {
+            return Err(NumeralError::InvalidDigit(0));
+        }
+        let numeral = numeral();
+        let digits = value
+            .char_indices()
+            .map(|(offset, c)| numeral.value(c).ok_or(NumeralError::InvalidDigit(offset)))
+            .collect::<Result<Vec<i64>, _>>()?;
+        Ok(Snafu::normalize(digits.into_iter().rev()))
+    }
+}
+
+impl Display for Snafu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let numeral = numeral();
+        let text: String = self
+            .digits
+            .iter()
+            .rev()
+            .map(|digit| numeral.symbol(*digit as i64).unwrap())
+            .collect();
+        write!(f, "{}", text)
+    }
+}
+
+impl From<isize> for Snafu {
+    fn from(value: isize) -> Self {
+        // every integer has balanced digits
+        let digits = numeral().digits(value as i128).unwrap();
+        Snafu::normalize(digits.into_iter().rev())
+    }
+}
+
+impl TryFrom<&Snafu> for isize {
+    type Error = NumeralError;
+
+    fn try_from(snafu: &Snafu) -> Result<Self, Self::Error> {
+        snafu.digits.iter().rev().try_fold(0isize, |value, digit| {
+            value
+                .checked_mul(5)
+                .and_then(|value| value.checked_add(*digit as isize))
+                .ok_or(NumeralError::OutOfRange)
+        })
+    }
+}
+
+impl Ord for Snafu {
+    // a difference in a digit outweighs all lower digits together,
+    // so the digits compare from the most significant one
+    fn cmp(&self, other: &Self) -> Ordering {
+        let len = self.digits.len().max(other.digits.len());
+        (0..len)
+            .rev()
+            .map(|index| self.digit(index).cmp(&other.digit(index)))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Snafu {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for &Snafu {
+    type Output = Snafu;
+
+    fn neg(self) -> Snafu {
+        Snafu {
+            digits: self.digits.iter().map(|digit| -digit).collect(),
+        }
+    }
+}
+
+impl Add for &Snafu {
+    type Output = Snafu;
+
+    fn add(self, other: &Snafu) -> Snafu {
+        let len = self.digits.len().max(other.digits.len());
+        Snafu::normalize((0..len).map(|index| self.digit(index) + other.digit(index)))
+    }
+}
+
+impl Sub for &Snafu {
+    type Output = Snafu;
+
+    fn sub(self, other: &Snafu) -> Snafu {
+        self + &-other
+    }
+}
+
+impl Mul for &Snafu {
+    type Output = Snafu;
+
+    fn mul(self, other: &Snafu) -> Snafu {
+        if self.is_zero() || other.is_zero() {
+            return Snafu::zero();
+        }
+        let mut values = vec![0; self.digits.len() + other.digits.len()];
+        for (i, a) in self.digits.iter().enumerate() {
+            for (j, b) in other.digits.iter().enumerate() {
+                values[i + j] += (*a as i64) * (*b as i64);
+            }
+        }
+        Snafu::normalize(values)
+    }
+}
+
+impl Neg for Snafu {
+    type Output = Snafu;
+
+    fn neg(self) -> Snafu {
+        -&self
+    }
+}
+
+impl Add for Snafu {
+    type Output = Snafu;
+
+    fn add(self, other: Snafu) -> Snafu {
+        &self + &other
+    }
+}
+
+impl Sub for Snafu {
+    type Output = Snafu;
+
+    fn sub(self, other: Snafu) -> Snafu {
+        &self - &other
+    }
+}
+
+impl Mul for Snafu {
+    type Output = Snafu;
+
+    fn mul(self, other: Snafu) -> Snafu {
+        &self * &other
+    }
+}
+
+impl<'a> Sum<&'a Snafu> for Snafu {
+    fn sum<I: Iterator<Item = &'a Snafu>>(iter: I) -> Snafu {
+        iter.fold(Snafu::zero(), |total, x| &total + x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::NumeralError;
+
+    use crate::snafu::Snafu;
+
+    fn snafu(value: &str) -> Snafu {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn zero_and_negative_numbers() {
+        assert_eq!(Snafu::from(0).to_string(), "0");
+        assert_eq!(snafu("0"), Snafu::zero());
+        assert_eq!(snafu("00"), Snafu::zero());
+        assert_eq!(snafu("012"), snafu("12"));
+        assert_eq!(Snafu::from(-1).to_string(), "-");
+        assert_eq!(Snafu::from(-3).to_string(), "-2");
+        assert_eq!(Snafu::from(-1747), -snafu("1=-0-2"));
+        for value in -200..200 {
+            let converted = isize::try_from(&Snafu::from(value));
+            assert_eq!(converted, Ok(value));
+        }
+    }
+
+    #[test]
+    fn invalid_digits() {
+        assert_eq!("".parse::<Snafu>(), Err(NumeralError::InvalidDigit(0)));
+        assert_eq!("12+1".parse::<Snafu>(), Err(NumeralError::InvalidDigit(2)));
+        assert_eq!("1 3".parse::<Snafu>(), Err(NumeralError::InvalidDigit(1)));
+    }
+
+    #[test]
+    fn arithmetic() {
+        for a in -30..30 {
+            for b in -30..30 {
+                let (x, y) = (Snafu::from(a), Snafu::from(b));
+                assert_eq!(&x + &y, Snafu::from(a + b), "{} + {}", a, b);
+                assert_eq!(&x - &y, Snafu::from(a - b), "{} - {}", a, b);
+                assert_eq!(&x * &y, Snafu::from(a * b), "{} * {}", a, b);
+                assert_eq!(x.cmp(&y), a.cmp(&b), "{} cmp {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn beyond_64_bits() {
+        // 5^30 is already more than 2^64
+        let big = snafu(&"2".repeat(30));
+        let sum = &big + &big;
+        assert_eq!(sum.to_string(), format!("1{}-", "0".repeat(29)));
+        assert_eq!(isize::try_from(&sum), Err(NumeralError::OutOfRange));
+        assert_eq!(&sum - &big, big);
+        assert!(sum > big && -&sum < big);
+        assert_eq!(isize::try_from(&(&big * &Snafu::zero())), Ok(0));
+    }
+}